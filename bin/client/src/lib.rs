@@ -23,6 +23,7 @@ use sunshine_bounty_client::{
     bank::Bank,
     bounty::Bounty,
     donate::Donate,
+    moloch::Moloch,
     org::Org,
     vote::Vote,
 };
@@ -95,6 +96,12 @@ impl Bank for Runtime {
     type SpendId = u64;
 }
 
+impl Moloch for Runtime {
+    type BankId = u64;
+    type SpendId = u64;
+    type MemId = u64;
+}
+
 impl Bounty for Runtime {
     type IpfsReference = sunshine_codec::Cid;
     type BountyId = u64;
@@ -5,9 +5,12 @@ use sp_core::{
     Pair,
     Public,
 };
-use sp_runtime::traits::{
-    IdentifyAccount,
-    Verify,
+use sp_runtime::{
+    traits::{
+        IdentifyAccount,
+        Verify,
+    },
+    Permill,
 };
 use sunshine_node_utils::node_service;
 pub use test_runtime::opaque::Block as OpaqueBlock;
@@ -20,6 +23,7 @@ use test_runtime::{
     GenesisConfig,
     GrandpaConfig,
     GrantConfig,
+    MolochConfig,
     OrgConfig,
     Signature,
     SystemConfig,
@@ -100,6 +104,8 @@ pub fn development_config() -> ChainSpec {
                     get_account_id_from_seed::<sr25519::Public>("Bob"),
                 ],
                 (10, 10),
+                // first org moloch bank deposit and spend proposals
+                (1 << 40, Vec::new()),
                 true,
             )
         },
@@ -156,6 +162,56 @@ pub fn local_testnet_config() -> ChainSpec {
                     get_account_id_from_seed::<sr25519::Public>("Ferdie"),
                 ],
                 (10, 10),
+                // first org moloch bank deposit and spend proposals
+                (1 << 40, Vec::new()),
+                true,
+            )
+        },
+        vec![],
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+pub fn moloch_demo_config() -> ChainSpec {
+    ChainSpec::from_genesis(
+        "Moloch Demo",
+        "moloch_demo",
+        ChainType::Local,
+        || {
+            testnet_genesis(
+                // initial authorities
+                vec![get_authority_keys_from_seed("Alice")],
+                // root key
+                get_account_id_from_seed::<sr25519::Public>("Alice"),
+                // endowed accounts
+                vec![
+                    get_account_id_from_seed::<sr25519::Public>("Alice"),
+                    get_account_id_from_seed::<sr25519::Public>("Bob"),
+                    get_account_id_from_seed::<sr25519::Public>("Charlie"),
+                    get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
+                ],
+                // first org value constitution
+                sunshine_codec::Cid::default(),
+                // first org flat membership
+                vec![
+                    get_account_id_from_seed::<sr25519::Public>("Alice"),
+                    get_account_id_from_seed::<sr25519::Public>("Bob"),
+                    get_account_id_from_seed::<sr25519::Public>("Charlie"),
+                ],
+                (10, 10),
+                // first org moloch bank deposit and a spend from Bob to Charlie
+                // waiting for a vote to be triggered
+                (
+                    1 << 40,
+                    vec![(
+                        get_account_id_from_seed::<sr25519::Public>("Bob"),
+                        1 << 30,
+                        get_account_id_from_seed::<sr25519::Public>("Charlie"),
+                    )],
+                ),
                 true,
             )
         },
@@ -174,6 +230,7 @@ pub fn testnet_genesis(
     first_org_value_constitution: sunshine_codec::Cid,
     first_org_flat_membership: Vec<AccountId>,
     treasury_mint_rate: (BlockNumber, Balance),
+    first_org_bank: (Balance, Vec<(AccountId, Balance, AccountId)>),
     _enable_println: bool,
 ) -> GenesisConfig {
     GenesisConfig {
@@ -185,6 +242,24 @@ pub fn testnet_genesis(
             application_poll_frequency: 10,
             milestone_poll_frequency: 10,
         }),
+        moloch: Some(MolochConfig {
+            spend_poll_frequency: 10,
+            member_poll_frequency: 10,
+            // bank for the first org, controlled by the root key
+            banks: vec![(
+                root_key.clone(),
+                1,
+                first_org_bank.0,
+                Some(root_key.clone()),
+                Permill::from_percent(51),
+            )],
+            initial_members: Vec::new(),
+            spends: first_org_bank
+                .1
+                .into_iter()
+                .map(|(proposer, amount, dest)| (1, proposer, amount, dest))
+                .collect(),
+        }),
         org: Some(OrgConfig {
             sudo: root_key,
             doc: first_org_value_constitution,
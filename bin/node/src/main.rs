@@ -62,6 +62,7 @@ impl SubstrateCli for Cli {
         Ok(match id {
             "dev" => Box::new(test_node::development_config()),
             "" | "local" => Box::new(test_node::local_testnet_config()),
+            "moloch" => Box::new(test_node::moloch_demo_config()),
             path => {
                 Box::new(test_node::ChainSpec::from_json_file(path.into())?)
             }
@@ -23,6 +23,7 @@ use sp_runtime::{
     generic,
     impl_opaque_keys,
     traits::{
+        AccountIdConversion,
         BlakeTwo256,
         Block as BlockT,
         IdentifyAccount,
@@ -375,6 +376,68 @@ impl grant::Trait for Runtime {
     type MinDeposit = MinGrantDeposit;
     type MinContribution = MinGrantContribution;
 }
+parameter_types! {
+    pub const MolochBank: ModuleId = ModuleId(*b"mol/bank");
+    pub const MolochMinDeposit: u128 = 20;
+    pub const SpendBond: u128 = 5;
+    pub const MinSpend: u128 = 1;
+    pub const ProcessingReward: u128 = 1;
+    pub const MaxBanksPerOrg: u32 = 4;
+    pub const MaxBankNameLen: u32 = 32;
+    pub const MaxAutomatedSpendsPerBank: u32 = 16;
+    pub const MaxSplitLegs: u32 = 16;
+    pub const MaxSharesPerMember: u64 = 1_000_000;
+    pub const MaxOpenProposalsPerMember: u32 = 8;
+    pub const GraceWindow: BlockNumber = 2 * DAYS;
+    pub const MaxProposalsPolledPerBlock: u32 = 64;
+    pub const ProposalTtl: BlockNumber = 7 * DAYS;
+    pub const MaxPageSize: u32 = 100;
+    pub const MaxActivityLog: u32 = 64;
+    pub const MaxBatchSummon: u32 = 8;
+    pub const ShareVestingPeriod: BlockNumber = 7 * DAYS;
+    pub const ConvictionLockPeriod: BlockNumber = DAYS;
+    pub const RequireSupervisorToSummon: bool = false;
+    pub const SpendFee: Permill = Permill::from_percent(0);
+}
+pub struct SpendFeeDestination;
+impl frame_support::traits::Get<AccountId> for SpendFeeDestination {
+    fn get() -> AccountId {
+        TreasuryModuleId::get().into_account()
+    }
+}
+impl moloch::Trait for Runtime {
+    type Event = Event;
+    type Currency = Balances;
+    type BigBank = MolochBank;
+    type BankId = u64;
+    type SpendId = u64;
+    type MemId = u64;
+    type MinDeposit = MolochMinDeposit;
+    type SpendBond = SpendBond;
+    type MinSpend = MinSpend;
+    type ProcessingReward = ProcessingReward;
+    type MaxBanksPerOrg = MaxBanksPerOrg;
+    type MaxBankNameLen = MaxBankNameLen;
+    type MaxAutomatedSpendsPerBank = MaxAutomatedSpendsPerBank;
+    type MaxSplitLegs = MaxSplitLegs;
+    type MaxSharesPerMember = MaxSharesPerMember;
+    type MaxOpenProposalsPerMember = MaxOpenProposalsPerMember;
+    type GraceWindow = GraceWindow;
+    type WeightInfo = ();
+    type MaxProposalsPolledPerBlock = MaxProposalsPolledPerBlock;
+    type ProposalTtl = ProposalTtl;
+    type MaxPageSize = MaxPageSize;
+    type MaxActivityLog = MaxActivityLog;
+    type MaxBatchSummon = MaxBatchSummon;
+    type ShareVestingPeriod = ShareVestingPeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type RequireSupervisorToSummon = RequireSupervisorToSummon;
+    type SpendFee = SpendFee;
+    type FeeDestination = SpendFeeDestination;
+    type SlashedBondTreasury = ();
+    type ExternalFlags = ();
+    type VoteMachine = Vote;
+}
 
 construct_runtime!(
     pub enum Runtime where
@@ -398,6 +461,7 @@ construct_runtime!(
         Bank: bank::{Module, Call, Storage, Event<T>},
         Bounty: bounty::{Module, Call, Storage, Event<T>},
         Grant: grant::{Module, Call, Config<T>, Storage, Event<T>},
+        Moloch: moloch::{Module, Call, Config<T>, Storage, Event<T>},
     }
 );
 
@@ -542,4 +606,126 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl moloch_runtime_api::MolochApi<
+        Block,
+        AccountId,
+        u64,
+        u64,
+        u64,
+        u64,
+        u64,
+        Balance,
+        u64,
+        u64,
+        BlockNumber,
+    > for Runtime {
+        fn bank_members(bank_id: u64, start: u32, limit: u32) -> Vec<(AccountId, u64)> {
+            Moloch::bank_members(bank_id, start, limit)
+        }
+        fn pending_actions_for_member(
+            bank_id: u64,
+            who: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<util::moloch::PendingAction<u64, u64, u64>> {
+            Moloch::pending_actions_for_member(bank_id, who, start, limit)
+        }
+        fn bank_shares(bank_id: u64) -> Option<(u64, Option<u64>)> {
+            Moloch::bank_shares(bank_id)
+        }
+        fn member_stats(bank_id: u64, who: AccountId) -> util::moloch::MemberStat<Balance> {
+            Moloch::member_stats(bank_id, who)
+        }
+        fn member_ownership(bank_id: u64, who: AccountId) -> Permill {
+            Moloch::member_ownership(bank_id, who)
+        }
+        fn member_voting_power(bank_id: u64, who: AccountId) -> Option<Permill> {
+            Moloch::member_voting_power(bank_id, who)
+        }
+        fn bank_membership(bank_id: u64) -> Vec<(AccountId, u64)> {
+            Moloch::bank_membership(bank_id)
+        }
+        fn spend_details(
+            bank_id: u64,
+            spend_id: u64,
+            viewer: AccountId,
+        ) -> Option<util::bank::SpendDetail<AccountId, Balance>> {
+            Moloch::spend_details(bank_id, spend_id, viewer)
+        }
+        fn unclaimed_dissolution(bank_id: u64, who: AccountId) -> Balance {
+            Moloch::unclaimed_dissolution(bank_id, who)
+        }
+        fn spend_proposals_for_bank(
+            bank_id: u64,
+            voting_only: bool,
+            start_after: Option<u64>,
+            limit: u32,
+        ) -> (
+            Vec<(
+                u64,
+                util::bank::SpendProposal<u64, u64, Balance, AccountId, util::bank::SpendState<u64>>,
+            )>,
+            Option<u64>,
+        ) {
+            Moloch::spend_proposals_for_bank(bank_id, voting_only, start_after, limit)
+        }
+        fn proposals_by(
+            who: AccountId,
+            start_after: Option<(u64, u64)>,
+            limit: u32,
+        ) -> (
+            Vec<(
+                (u64, u64),
+                util::bank::SpendProposal<u64, u64, Balance, AccountId, util::bank::SpendState<u64>>,
+            )>,
+            Option<(u64, u64)>,
+        ) {
+            Moloch::proposals_by(who, start_after, limit)
+        }
+        fn member_proposals_for_bank(
+            bank_id: u64,
+            voting_only: bool,
+            start_after: Option<u64>,
+            limit: u32,
+        ) -> (
+            Vec<(
+                u64,
+                util::moloch::MembershipProposal<
+                    u64,
+                    u64,
+                    Balance,
+                    u64,
+                    AccountId,
+                    util::moloch::ProposalState<u64>,
+                >,
+            )>,
+            Option<u64>,
+        ) {
+            Moloch::member_proposals_for_bank(bank_id, voting_only, start_after, limit)
+        }
+        fn can_execute_spend(
+            bank_id: u64,
+            spend_id: u64,
+        ) -> Result<(), sp_runtime::DispatchError> {
+            Moloch::can_execute_spend(bank_id, spend_id)
+        }
+        fn bank_activity(
+            bank_id: u64,
+        ) -> Vec<(BlockNumber, util::moloch::BankActivity<u64, u64, u64>)> {
+            Moloch::bank_activity(bank_id)
+        }
+        fn bank_snapshot(
+            bank_id: u64,
+        ) -> Option<util::moloch::BankSnapshot<
+            util::bank::BankState<u64, AccountId, u64, u64>,
+            u64,
+            u64,
+            u64,
+            Balance,
+            BlockNumber,
+        >> {
+            Moloch::bank_snapshot(bank_id)
+        }
+    }
 }
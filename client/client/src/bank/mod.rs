@@ -174,3 +174,95 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use parity_scale_codec::{
+        Decode,
+        Encode,
+    };
+    use substrate_subxt::sp_runtime::Permill;
+    use test_client::{
+        bank::{
+            AccountOpenedEvent,
+            BalanceOf,
+            BankClient,
+            SpendProposedEvent,
+        },
+        client::{
+            AccountKeyring,
+            Client as _,
+            Node as _,
+        },
+        utils::{
+            organization::OrgRep,
+            vote::{
+                Threshold,
+                ThresholdInput,
+                XorThreshold,
+            },
+        },
+        Client,
+        Node,
+        Runtime,
+    };
+
+    // mirrors `test_runtime::Balance`, the runtime's `BalanceOf` for the bank pallet
+    type RuntimeBalance = u128;
+
+    #[test]
+    fn balance_round_trips_runtime_encoding() {
+        let runtime_balance: RuntimeBalance = 1 << 60;
+        let encoded = runtime_balance.encode();
+        let decoded = BalanceOf::<Runtime>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(decoded, runtime_balance);
+        assert_eq!(decoded.encode(), encoded);
+    }
+
+    #[test]
+    fn bank_events_decode_runtime_balance() {
+        let alice = AccountKeyring::Alice.to_account_id();
+        let bob = AccountKeyring::Bob.to_account_id();
+        let seed: RuntimeBalance = 1_000_000_000_000;
+        // same field order as `RawEvent::AccountOpened` in the bank pallet
+        let raw = (alice.clone(), 1u64, seed, 1u64, Some(bob.clone())).encode();
+        let opened =
+            AccountOpenedEvent::<Runtime>::decode(&mut &raw[..]).unwrap();
+        assert_eq!(opened.seeder, alice);
+        assert_eq!(opened.new_bank_id, 1);
+        assert_eq!(opened.seed, seed);
+        assert_eq!(opened.hosting_org, 1);
+        assert_eq!(opened.bank_operator, Some(bob.clone()));
+        // same field order as `RawEvent::SpendProposed` in the bank pallet
+        let amount: RuntimeBalance = u128::MAX - 1;
+        let raw = (alice.clone(), 1u64, 2u64, amount, bob.clone()).encode();
+        let proposed =
+            SpendProposedEvent::<Runtime>::decode(&mut &raw[..]).unwrap();
+        assert_eq!(proposed.amount, amount);
+        assert_eq!(proposed.dest, bob);
+    }
+
+    #[async_std::test]
+    async fn bank_fetched_by_pallet_key() {
+        let node = Node::new_mock();
+        let (client, _tmp) = Client::mock(&node, AccountKeyring::Alice).await;
+        let alice_account_id = AccountKeyring::Alice.to_account_id();
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        let opened = client
+            .open(1000, 1, Some(alice_account_id.clone()), threshold)
+            .await
+            .unwrap();
+        // the pallet stores banks under the `BankId` it emits on open
+        let bank = client.bank(opened.new_bank_id).await.unwrap();
+        assert_eq!(bank.id(), opened.new_bank_id);
+        assert_eq!(bank.org(), 1);
+        assert_eq!(bank.controller(), Some(alice_account_id));
+        let banks = client.banks_for_org(1).await.unwrap().unwrap();
+        assert!(banks
+            .iter()
+            .any(|(id, b)| *id == opened.new_bank_id && *b == bank));
+    }
+}
@@ -53,6 +53,8 @@ use sunshine_bounty_utils::{
     },
 };
 
+/// Must match the runtime's `BalanceOf<T>` for the bank pallet, which is
+/// `<T as Balances>::Balance` because the pallet's `Currency` is `Balances`
 pub type BalanceOf<T> = <T as Balances>::Balance;
 pub type BankSt<T> = BankState<
     <T as Bank>::BankId,
@@ -6,4 +6,10 @@ pub enum Error {
     EventNotFound,
     #[error("Number cannot be parsed from string")]
     ParseIntError,
+    #[error(
+        "spend proposal was polled but did not conclude before the timeout"
+    )]
+    SpendProposalWatchTimedOut,
+    #[error("spend proposal was never polled before the timeout")]
+    SpendProposalNeverPolled,
 }
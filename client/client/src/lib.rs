@@ -5,6 +5,7 @@ pub use error::Error;
 pub mod bank;
 pub mod bounty;
 pub mod donate;
+pub mod moloch;
 pub mod org;
 pub mod vote;
 pub use sunshine_bounty_utils as utils;
@@ -0,0 +1,106 @@
+mod subxt;
+
+pub use subxt::*;
+
+use crate::{
+    error::Error,
+    vote::Vote,
+};
+use parity_scale_codec::Decode;
+use substrate_subxt::{
+    events::Raw,
+    Event,
+};
+use sunshine_bounty_utils::bank::SpendState;
+use sunshine_client_utils::{
+    async_trait,
+    Client,
+    Node,
+    Result,
+};
+
+#[async_trait]
+pub trait MolochClient<N: Node>: Client<N>
+where
+    N::Runtime: Moloch,
+{
+    /// Waits up to `timeout_blocks` finalized blocks for the spend proposal to be
+    /// polled into `ApprovedAndExecuted`, `ApprovedButNotExecuted` or `Rejected`
+    async fn watch_spend_proposal(
+        &self,
+        bank_id: <N::Runtime as Moloch>::BankId,
+        spend_id: <N::Runtime as Moloch>::SpendId,
+        timeout_blocks: u32,
+    ) -> Result<SpendState<<N::Runtime as Vote>::VoteId>>;
+}
+
+#[async_trait]
+impl<N, C> MolochClient<N> for C
+where
+    N: Node,
+    N::Runtime: Moloch,
+    C: Client<N>,
+{
+    async fn watch_spend_proposal(
+        &self,
+        bank_id: <N::Runtime as Moloch>::BankId,
+        spend_id: <N::Runtime as Moloch>::SpendId,
+        timeout_blocks: u32,
+    ) -> Result<SpendState<<N::Runtime as Vote>::VoteId>> {
+        let client = self.chain_client();
+        let mut sub = client.subscribe_finalized_events().await?;
+        let mut seen = false;
+        // the events storage changes once per finalized block
+        for _ in 0..timeout_blocks {
+            let change_set = match sub.next().await {
+                Some(change_set) => change_set,
+                None => break,
+            };
+            for (_, data) in change_set.changes {
+                let data = if let Some(data) = data {
+                    data
+                } else {
+                    continue
+                };
+                for (_, raw) in
+                    client.events_decoder().decode_events(&mut &data.0[..])?
+                {
+                    let event = match raw {
+                        Raw::Event(event) => event,
+                        Raw::Error(_) => continue,
+                    };
+                    if event.module
+                        != <SpendProposalPolledEvent<N::Runtime> as Event<
+                            N::Runtime,
+                        >>::MODULE
+                        || event.variant
+                            != <SpendProposalPolledEvent<N::Runtime> as Event<
+                                N::Runtime,
+                            >>::EVENT
+                    {
+                        continue
+                    }
+                    let polled =
+                        SpendProposalPolledEvent::<N::Runtime>::decode(
+                            &mut &event.data[..],
+                        )?;
+                    if polled.bank_id != bank_id || polled.spend_id != spend_id
+                    {
+                        continue
+                    }
+                    seen = true;
+                    if polled.state.is_terminal()
+                        || polled.state == SpendState::ApprovedButNotExecuted
+                    {
+                        return Ok(polled.state)
+                    }
+                }
+            }
+        }
+        if seen {
+            Err(Error::SpendProposalWatchTimedOut.into())
+        } else {
+            Err(Error::SpendProposalNeverPolled.into())
+        }
+    }
+}
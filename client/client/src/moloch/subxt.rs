@@ -0,0 +1,225 @@
+use crate::{
+    donate::{
+        Donate,
+        DonateEventsDecoder,
+    },
+    org::{
+        Org,
+        OrgEventsDecoder,
+    },
+    vote::{
+        Vote,
+        VoteEventsDecoder,
+    },
+};
+use frame_support::Parameter;
+use parity_scale_codec::{
+    Codec,
+    Decode,
+    Encode,
+};
+use sp_runtime::traits::{
+    AtLeast32Bit,
+    MaybeSerializeDeserialize,
+    Member,
+    Zero,
+};
+use std::fmt::Debug;
+use substrate_subxt::{
+    balances::{
+        Balances,
+        BalancesEventsDecoder,
+    },
+    module,
+    sp_runtime,
+    system::{
+        System,
+        SystemEventsDecoder,
+    },
+    Call,
+    Event,
+    Store,
+};
+use sunshine_bounty_utils::{
+    bank::{
+        SpendProposal,
+        SpendState,
+    },
+    moloch::{
+        MembershipProposal,
+        ProposalState,
+        ResolutionTrigger,
+    },
+    organization::OrgRep,
+    vote::{
+        ThresholdInput,
+        XorThreshold,
+    },
+};
+
+/// Must match the runtime's `BalanceOf<T>` for the moloch pallet, which is
+/// `<T as Balances>::Balance` because the pallet's `Currency` is `Balances`
+pub type BalanceOf<T> = <T as Balances>::Balance;
+pub type Threshold<T> = ThresholdInput<
+    OrgRep<<T as Org>::OrgId>,
+    XorThreshold<<T as Vote>::Signal, <T as Vote>::Percent>,
+>;
+pub type SpendProp<T> = SpendProposal<
+    <T as Moloch>::BankId,
+    <T as Moloch>::SpendId,
+    BalanceOf<T>,
+    <T as System>::AccountId,
+    SpendState<<T as Vote>::VoteId>,
+>;
+pub type MemberProp<T> = MembershipProposal<
+    <T as Moloch>::BankId,
+    <T as Moloch>::MemId,
+    BalanceOf<T>,
+    <T as Org>::Shares,
+    <T as System>::AccountId,
+    ProposalState<<T as Vote>::VoteId>,
+>;
+
+#[module]
+pub trait Moloch: System + Balances + Org + Vote + Donate {
+    type BankId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+    type SpendId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+    type MemId: Parameter
+        + Member
+        + AtLeast32Bit
+        + Codec
+        + Default
+        + Copy
+        + MaybeSerializeDeserialize
+        + Debug
+        + PartialOrd
+        + PartialEq
+        + Zero;
+}
+
+// ~~ Maps ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendPropsStore<T: Moloch> {
+    #[store(returns = SpendProp<T>)]
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct MemberPropsStore<T: Moloch> {
+    #[store(returns = MemberProp<T>)]
+    pub bank_id: T::BankId,
+    pub mem_id: T::MemId,
+}
+
+// ~~ (Calls, Events) ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SummonCall<T: Moloch> {
+    pub org: <T as Org>::OrgId,
+    pub deposit: BalanceOf<T>,
+    pub controller: Option<<T as System>::AccountId>,
+    pub threshold: Threshold<T>,
+    pub name: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AccountOpenedEvent<T: Moloch> {
+    pub opener: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub deposit: BalanceOf<T>,
+    pub org: <T as Org>::OrgId,
+    pub controller: Option<<T as System>::AccountId>,
+    pub name: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct ProposeSpendCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+    pub metadata: Option<<T as System>::Hash>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendProposedEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub amount: BalanceOf<T>,
+    pub dest: <T as System>::AccountId,
+    pub fee: BalanceOf<T>,
+    pub metadata: Option<<T as System>::Hash>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SpendTriggerVoteCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendVoteTriggeredEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub vote_id: <T as Vote>::VoteId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct SudoApproveSpendProposalCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendSudoApprovedEvent<T: Moloch> {
+    pub caller: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct SpendProposalPolledEvent<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub spend_id: T::SpendId,
+    pub state: SpendState<<T as Vote>::VoteId>,
+    pub tally: Option<(<T as Vote>::Signal, <T as Vote>::Signal)>,
+    pub trigger: ResolutionTrigger<<T as System>::AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
+pub struct CloseOrgBankAccountCall<T: Moloch> {
+    pub bank_id: T::BankId,
+    pub force: bool,
+    pub members: u32,
+    pub proposals: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct AccountClosedEvent<T: Moloch> {
+    pub closer: <T as System>::AccountId,
+    pub bank_id: T::BankId,
+    pub org: <T as Org>::OrgId,
+}
@@ -436,7 +436,8 @@ impl<T: Trait>
             Error::<T>::NotPermittedToProposeSpendForBankAccount
         );
         let id = Self::generate_spend_uid(bank_id);
-        let proposal = SpendProposal::new(bank_id, id, amount, dest);
+        let proposal =
+            SpendProposal::new(bank_id, id, caller.clone(), amount, dest);
         <SpendProposals<T>>::insert(bank_id, id, proposal);
         Ok(id)
     }
@@ -0,0 +1,137 @@
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+#![cfg_attr(not(feature = "std"), no_std)]
+//! Runtime API for the moloch module
+
+use parity_scale_codec::Codec;
+use sp_runtime::{
+    traits::Zero,
+    DispatchError,
+    Permill,
+};
+use sp_std::prelude::*;
+use util::{
+    bank::{
+        BankState,
+        SpendDetail,
+        SpendProposal,
+        SpendState,
+    },
+    moloch::{
+        BankActivity,
+        BankSnapshot,
+        MemberStat,
+        MembershipProposal,
+        PendingAction,
+        ProposalState,
+    },
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait MolochApi<
+        AccountId,
+        BankId,
+        SpendId,
+        MemId,
+        VoteId,
+        Shares,
+        Balance,
+        OrgId,
+        ThresholdId,
+        BlockNumber,
+    > where
+        AccountId: Codec,
+        BankId: Codec,
+        SpendId: Codec,
+        MemId: Codec,
+        VoteId: Codec,
+        Shares: Codec,
+        Balance: Codec,
+        OrgId: Codec + PartialEq + Zero + From<u32> + Copy,
+        ThresholdId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Page of the bank's members (ordered by account) paired with their shares
+        fn bank_members(bank_id: BankId, start: u32, limit: u32) -> Vec<(AccountId, Shares)>;
+        /// Page of the bank's live votes in which `who` can vote but has not yet
+        fn pending_actions_for_member(
+            bank_id: BankId,
+            who: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<PendingAction<SpendId, MemId, VoteId>>;
+        /// Outstanding shares of the bank's org and the bank's cap on them
+        fn bank_shares(bank_id: BankId) -> Option<(Shares, Option<Shares>)>;
+        /// Contribution history of the account in the bank
+        fn member_stats(bank_id: BankId, who: AccountId) -> MemberStat<Balance>;
+        /// Share of the bank's org owned by `who`
+        fn member_ownership(bank_id: BankId, who: AccountId) -> Permill;
+        /// Voting weight of `who` in the bank's org, `None` if not a member
+        fn member_voting_power(bank_id: BankId, who: AccountId) -> Option<Permill>;
+        /// Every member of the bank's org paired with their shares
+        fn bank_membership(bank_id: BankId) -> Vec<(AccountId, Shares)>;
+        /// Proposer and bond of a live spend proposal, only for members and observers
+        fn spend_details(
+            bank_id: BankId,
+            spend_id: SpendId,
+            viewer: AccountId,
+        ) -> Option<SpendDetail<AccountId, Balance>>;
+        /// Close distribution escrowed for `who` after their transfer failed
+        fn unclaimed_dissolution(bank_id: BankId, who: AccountId) -> Balance;
+        /// Page of the bank's spend proposals after `start_after`, only those in a live
+        /// vote if `voting_only`, with the cursor for the next page
+        fn spend_proposals_for_bank(
+            bank_id: BankId,
+            voting_only: bool,
+            start_after: Option<SpendId>,
+            limit: u32,
+        ) -> (
+            Vec<(SpendId, SpendProposal<BankId, SpendId, Balance, AccountId, SpendState<VoteId>>)>,
+            Option<SpendId>,
+        );
+        /// Page of the spend proposals made by `who` across all banks after `start_after`,
+        /// with the cursor for the next page
+        fn proposals_by(
+            who: AccountId,
+            start_after: Option<(BankId, SpendId)>,
+            limit: u32,
+        ) -> (
+            Vec<(
+                (BankId, SpendId),
+                SpendProposal<BankId, SpendId, Balance, AccountId, SpendState<VoteId>>,
+            )>,
+            Option<(BankId, SpendId)>,
+        );
+        /// Page of the bank's membership proposals after `start_after`, only those in a
+        /// live vote if `voting_only`, with the cursor for the next page
+        fn member_proposals_for_bank(
+            bank_id: BankId,
+            voting_only: bool,
+            start_after: Option<MemId>,
+            limit: u32,
+        ) -> (
+            Vec<(
+                MemId,
+                MembershipProposal<BankId, MemId, Balance, Shares, AccountId, ProposalState<VoteId>>,
+            )>,
+            Option<MemId>,
+        );
+        /// Why the spend would not be paid if executed now, `Ok` if it would
+        fn can_execute_spend(bank_id: BankId, spend_id: SpendId) -> Result<(), DispatchError>;
+        /// Recent activity of the bank with the block it happened in, oldest first
+        fn bank_activity(
+            bank_id: BankId,
+        ) -> Vec<(BlockNumber, BankActivity<SpendId, MemId, VoteId>)>;
+        /// State, balances, live proposals and configuration of the bank in one read
+        fn bank_snapshot(
+            bank_id: BankId,
+        ) -> Option<BankSnapshot<
+            BankState<BankId, AccountId, OrgId, ThresholdId>,
+            SpendId,
+            MemId,
+            ThresholdId,
+            Balance,
+            BlockNumber,
+        >>;
+    }
+}
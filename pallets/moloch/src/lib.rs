@@ -6,6 +6,7 @@
 
 #[cfg(test)]
 mod tests;
+pub mod weights;
 
 use frame_support::{
     decl_error,
@@ -14,35 +15,48 @@ use frame_support::{
     decl_storage,
     ensure,
     storage::{
+        with_transaction,
         IterableStorageDoubleMap,
         IterableStorageMap,
     },
     traits::{
+        BalanceStatus,
         Currency,
         ExistenceRequirement,
         Get,
+        OnUnbalanced,
         ReservableCurrency,
+        WithdrawReason,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
+    ensure_root,
     ensure_signed,
     Trait as System,
 };
 use org::Trait as Org;
 use parity_scale_codec::Codec;
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational,
     traits::{
         AccountIdConversion,
         AtLeast32Bit,
+        CheckedAdd,
+        CheckedSub,
+        Hash,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
     ModuleId,
     Permill,
+    SaturatedConversion,
+    TransactionOutcome,
 };
 use sp_std::{
     fmt::Debug,
@@ -51,34 +65,58 @@ use sp_std::{
 use util::{
     bank::{
         BankState,
+        CloseDustDestination,
+        RecurringSchedule,
+        SlashedBondDestination,
+        SpendCondition,
+        SpendDetail,
         SpendProposal,
         SpendState,
     },
     moloch::{
+        BankActivity,
+        BankSnapshot,
+        KickProposal,
+        MemberStat,
         MembershipProposal,
+        PendingAction,
         ProposalState,
+        ResolutionTrigger,
     },
     organization::OrgRep,
     traits::{
+        CloseVote,
         ConfigureThreshold,
+        ConvictionVote,
+        ExternalFlag,
+        GetGroup,
         GetVoteOutcome,
         GroupMembership,
         MolochMembership,
         OpenBankAccount,
+        OrganizationSupervisorPermissions,
+        Ragequit,
+        ShareInformation,
         ShareIssuance,
         SpendGovernance,
+        VoteParticipation,
     },
     vote::{
+        Threshold as VoteThreshold,
         ThresholdInput,
         VoteOutcome,
         XorThreshold,
     },
 };
 use vote::Trait as Vote;
+pub use weights::WeightInfo;
 
 // type aliases
 type BalanceOf<T> =
     <<T as Trait>::Currency as Currency<<T as System>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Trait>::Currency as Currency<
+    <T as System>::AccountId,
+>>::NegativeImbalance;
 type BankSt<T> = BankState<
     <T as Trait>::BankId,
     <T as System>::AccountId,
@@ -96,6 +134,10 @@ type SpendProp<T> = SpendProposal<
     <T as System>::AccountId,
     SpendState<<T as Vote>::VoteId>,
 >;
+type SpendCond<T> =
+    SpendCondition<<T as System>::BlockNumber, <T as System>::Hash>;
+type RecurringSched<T> =
+    RecurringSchedule<BalanceOf<T>, <T as System>::BlockNumber>;
 type MemberProp<T> = MembershipProposal<
     <T as Trait>::BankId,
     <T as Trait>::MemId,
@@ -104,6 +146,17 @@ type MemberProp<T> = MembershipProposal<
     <T as System>::AccountId,
     ProposalState<<T as Vote>::VoteId>,
 >;
+type Activity<T> = BankActivity<
+    <T as Trait>::SpendId,
+    <T as Trait>::MemId,
+    <T as Vote>::VoteId,
+>;
+type KickProp<T> = KickProposal<
+    <T as Trait>::BankId,
+    <T as Trait>::MemId,
+    <T as System>::AccountId,
+    ProposalState<<T as Vote>::VoteId>,
+>;
 
 pub trait Trait: System + Org + donate::Trait + Vote {
     /// The overarching event types
@@ -157,49 +210,270 @@ pub trait Trait: System + Org + donate::Trait + Vote {
 
     /// The minimum amount to open an organizational bank account and keep it open
     type MinDeposit: Get<BalanceOf<Self>>;
+
+    /// Bond reserved from the spend proposer, returned unless the spend is rejected
+    type SpendBond: Get<BalanceOf<Self>>;
+
+    /// The minimum amount a spend proposal may request
+    type MinSpend: Get<BalanceOf<Self>>;
+
+    /// Paid from the bank to the member who processes a concluded vote ahead of polling
+    type ProcessingReward: Get<BalanceOf<Self>>;
+
+    /// Maximum number of banks each org may open
+    type MaxBanksPerOrg: Get<u32>;
+
+    /// Maximum length in bytes of a bank's name
+    type MaxBankNameLen: Get<u32>;
+
+    /// Maximum unresolved conditional spends per bank, bounding the retries when polling
+    type MaxAutomatedSpendsPerBank: Get<u32>;
+
+    /// Maximum payouts in a split spend, bounding its execution weight
+    type MaxSplitLegs: Get<u32>;
+
+    /// Default cap on the shares a single member may hold, banks may override it
+    type MaxSharesPerMember: Get<Self::Shares>;
+
+    /// Maximum spend and membership proposals a member may have open in each bank
+    type MaxOpenProposalsPerMember: Get<u32>;
+
+    /// Blocks an approved spend waits before payout, during which the controller
+    /// may veto it (zero executes on approval)
+    type GraceWindow: Get<Self::BlockNumber>;
+
+    /// Weights of the dispatchable calls
+    type WeightInfo: WeightInfo;
+
+    /// Maximum proposals polled in a block, any backlog is drained over the following blocks
+    type MaxProposalsPolledPerBlock: Get<u32>;
+
+    /// Blocks a spend or membership proposal may wait for a vote before it expires
+    type ProposalTtl: Get<Self::BlockNumber>;
+
+    /// Maximum proposals returned in one page of a proposal listing
+    type MaxPageSize: Get<u32>;
+
+    /// Number of recent entries kept in each bank's activity log
+    type MaxActivityLog: Get<u32>;
+
+    /// Maximum banks opened by one batch summon
+    type MaxBatchSummon: Get<u32>;
+
+    /// Blocks shares minted by a membership proposal stay locked, during which they
+    /// cannot be ragequit or used to trigger votes (zero disables the lock)
+    type ShareVestingPeriod: Get<Self::BlockNumber>;
+
+    /// Blocks in one conviction lock period for spend votes in banks with conviction enabled
+    type ConvictionLockPeriod: Get<Self::BlockNumber>;
+
+    /// Only the org supervisor may open banks for the org, instead of any member
+    type RequireSupervisorToSummon: Get<bool>;
+
+    /// Share of each executed spend routed to `FeeDestination` instead of the payee
+    type SpendFee: Get<Permill>;
+
+    /// Receives the fee charged on executed spends
+    type FeeDestination: Get<Self::AccountId>;
+
+    /// Receives rejected spend bonds for banks configured to slash into the treasury
+    type SlashedBondTreasury: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+    /// Flags read when evaluating `SpendCondition::IfExternalFlag`
+    type ExternalFlags: ExternalFlag<Self::Hash>;
+
+    /// The voting backend, usually `vote::Module<Self>`
+    type VoteMachine: ConfigureThreshold<
+            Threshold<Self>,
+            <Self as Org>::Cid,
+            Self::BlockNumber,
+            ThresholdId = Self::ThresholdId,
+            VoteId = Self::VoteId,
+        > + GetVoteOutcome<Self::VoteId, Outcome = VoteOutcome>
+        + VoteParticipation<Self::VoteId, Self::AccountId, Self::Signal>
+        + CloseVote<Self::VoteId>
+        + ConvictionVote<
+            <Self as Org>::OrgId,
+            Self::VoteId,
+            Self::AccountId,
+            Self::BlockNumber,
+        >;
 }
 
 decl_event!(
     pub enum Event<T>
     where
         <T as System>::AccountId,
+        <T as System>::BlockNumber,
+        <T as System>::Hash,
         <T as Org>::OrgId,
         <T as Org>::Shares,
         <T as Vote>::VoteId,
+        <T as Vote>::ThresholdId,
+        <T as Vote>::Signal,
         <T as Trait>::BankId,
         <T as Trait>::SpendId,
         <T as Trait>::MemId,
         Balance = BalanceOf<T>,
     {
-        AccountOpened(AccountId, BankId, Balance, OrgId, Option<AccountId>),
-        MemberProposed(AccountId, BankId, MemId, Balance, Shares, AccountId),
-        SpendProposed(AccountId, BankId, SpendId, Balance, AccountId),
+        AccountOpened(AccountId, BankId, Balance, OrgId, Option<AccountId>, Option<Vec<u8>>),
+        // opener, banks opened in order, number of specs in the batch
+        BanksBatchSummoned(AccountId, Vec<BankId>, u32),
+        // member, bank, amt deposited on top of the summon deposit
+        BankFunded(AccountId, BankId, Balance),
+        // tribute, shares, loot, applicant, proposal fee paid into the bank, then the
+        // hash of the off-chain metadata
+        MemberProposed(AccountId, BankId, MemId, Balance, Shares, Shares, AccountId, Balance, Option<Hash>),
+        SpendProposed(AccountId, BankId, SpendId, Balance, AccountId, Balance, Option<Hash>),
         MemberVoteTriggered(AccountId, BankId, MemId, VoteId),
         SpendVoteTriggered(AccountId, BankId, SpendId, VoteId),
         SpendSudoApproved(AccountId, BankId, SpendId),
-        SpendProposalPolled(BankId, SpendId, SpendState<VoteId>),
-        MemberProposalPolled(BankId, MemId, ProposalState<VoteId>),
+        // live vote cancelled because the controller approved the spend mid-vote
+        LiveVoteCancelledBySudo(BankId, SpendId, VoteId),
+        // bank, spend, state, tally of a vote the poll concluded, what triggered the poll
+        SpendProposalPolled(BankId, SpendId, SpendState<VoteId>, Option<(Signal, Signal)>, ResolutionTrigger<AccountId>),
+        SpendProposalCancelled(AccountId, BankId, SpendId),
+        SpendProposalAmended(BankId, SpendId),
+        // removed after outliving its expiry without a live vote
+        SpendProposalExpired(BankId, SpendId),
+        MemberProposalExpired(BankId, MemId),
+        // bank, spend, whether the retried spend executed
+        SpendExecutionRetried(BankId, SpendId, bool),
+        // bank, spend vote threshold, membership vote threshold (`None` uses the bank's)
+        BankThresholdsSet(BankId, Option<ThresholdId>, Option<ThresholdId>),
+        MemberProposalPolled(BankId, MemId, ProposalState<VoteId>, Option<(Signal, Signal)>, ResolutionTrigger<AccountId>),
+        // proposer, bank, kick proposal, member to be kicked
+        GuildKickProposed(AccountId, BankId, MemId, AccountId),
+        KickVoteTriggered(AccountId, BankId, MemId, VoteId),
+        KickProposalPolled(BankId, MemId, ProposalState<VoteId>, Option<(Signal, Signal)>, ResolutionTrigger<AccountId>),
+        KickProposalExpired(BankId, MemId),
+        // bank, kick proposal, kicked member, voting shares converted to loot
+        GuildKickExecuted(BankId, MemId, AccountId, Shares),
         // relevant org and number of shares burned
         SharesBurned(OrgId, Shares),
         // bank, amt withdrawn by burn, amt left in bank
         WithdrawnPortion(BankId, Balance, Balance),
         AccountClosed(AccountId, BankId, OrgId),
+        // bank closed by root without its controller
+        BankForceClosed(BankId, OrgId),
+        // source bank removed, target bank, balance moved into the target
+        BanksMerged(BankId, BankId, Balance),
+        DestAllowlistRequired(BankId, bool),
+        DestAllowlisted(BankId, AccountId),
+        DestRemovedFromAllowlist(BankId, AccountId),
+        BankQuorumSet(BankId, Option<Permill>),
+        // vote approved on support but turnout fell short of the bank quorum
+        ProposalFailedQuorum(BankId, VoteId),
+        SlashedBondDestinationSet(BankId, SlashedBondDestination),
+        SpendBondReturned(BankId, SpendId, AccountId, Balance),
+        SpendBondSlashed(BankId, SpendId, AccountId, Balance, SlashedBondDestination),
+        ReproposalCooloffSet(BankId, BlockNumber),
+        // approved but the spend condition does not hold yet
+        ConditionalSpendPending(BankId, SpendId),
+        ConditionalSpendExecuted(BankId, SpendId, Balance, AccountId),
+        RagequitFeeSet(BankId, Permill),
+        TributeMatchSet(BankId, Permill),
+        // bank, minimum tribute per requested share (none if unenforced)
+        TributePolicySet(BankId, Option<Balance>),
+        ConvictionEnabledSet(BankId, bool),
+        // bank, proposal, applicant, bonus shares minted on top of those requested
+        TributeMatched(BankId, MemId, AccountId, Shares),
+        // bank, member, shares locked in total, block they unlock at
+        SharesLocked(BankId, AccountId, Shares, BlockNumber),
+        // member, bank, shares burned, amt paid out, fee retained by bank
+        MemberRagequit(AccountId, BankId, Shares, Balance, Balance),
+        CloseDustDestinationSet(BankId, CloseDustDestination),
+        // residual swept from the bank account after the close distribution
+        CloseDustSwept(BankId, Balance, CloseDustDestination),
+        MaxTotalSharesSet(BankId, Option<Shares>),
+        // `None` restores the module default
+        MaxSharesPerMemberSet(BankId, Option<Shares>),
+        // bank, member, loot burned, amt paid out
+        LootClaimed(BankId, AccountId, Shares, Balance),
+        // member's share of the close distribution that could not be transferred
+        DissolutionProceedsEscrowed(BankId, AccountId, Balance),
+        DissolutionProceedsClaimed(BankId, AccountId, Balance),
+        ProposalFeeSet(BankId, Balance),
+        MaxVoteAgeSet(BankId, Option<BlockNumber>),
+        // vote outlived the bank's max vote age and was resolved on current tallies
+        VoteForceResolved(BankId, VoteId),
+        // keeper, proposals advanced, whether active proposals were left unread
+        ConcludedProposalsPoked(AccountId, u32, bool),
+        BankRenamed(BankId, Option<Vec<u8>>),
+        // bank, old controller, new controller (`None` leaves the bank without sudo)
+        BankControlTransferred(BankId, Option<AccountId>, Option<AccountId>),
+        SponsorBondSet(BankId, Balance),
+        ObserverAdded(BankId, AccountId),
+        ObserverRemoved(BankId, AccountId),
+        // bank, member, delegate now allowed to trigger votes for them
+        DelegateSet(BankId, AccountId, Option<AccountId>),
+        SponsorBondReturned(BankId, MemId, AccountId, Balance),
+        // slashed into the bank when the sponsored membership is rejected
+        SponsorBondSlashed(BankId, MemId, AccountId, Balance),
+        // escrowed tribute released to the applicant of an unsuccessful proposal
+        TributeRefunded(BankId, MemId, AccountId, Balance),
+        // escrowed tribute released to the applicant when the proposal expired
+        ExpiredMembershipTributeRefunded(BankId, MemId, AccountId, Balance),
+        // bank, spend poll frequency, member poll frequency
+        PollFrequencySet(BankId, BlockNumber, BlockNumber),
+        // proposer, bank, spend, total amount, number of payouts
+        SplitSpendProposed(AccountId, BankId, SpendId, Balance, u32),
+        // bank, spend, legs transferred in this execution
+        SplitSpendExecuted(BankId, SpendId, u32),
+        // proposer, bank, spend, amount to distribute
+        DividendProposed(AccountId, BankId, SpendId, Balance),
+        // bank, amount paid out to holders
+        DividendDistributed(BankId, Balance),
+        // proposer, paying bank, spend, receiving bank, amount
+        InterBankSpendProposed(AccountId, BankId, SpendId, BankId, Balance),
+        // paying bank, spend, receiving bank, amount
+        InterBankSpendExecuted(BankId, SpendId, BankId, Balance),
+        // proposer, bank, spend, amount per period, period, number of periods
+        RecurringSpendProposed(AccountId, BankId, SpendId, Balance, BlockNumber, u32),
+        // bank, spend, installment number, amount paid (zero if the transfer failed)
+        RecurringSpendPayout(BankId, SpendId, u32, Balance),
+        // bank, spend, block from which the next poll executes it
+        SpendEnteredGracePeriod(BankId, SpendId, BlockNumber),
+        // bank, cap on executed spends and the window it applies to (`None` lifts it)
+        SpendCapSet(BankId, Option<(Balance, BlockNumber)>),
+        // an approved spend was deferred to the next window by the bank's spend cap
+        SpendCapReached(BankId),
+        // bank, spend, why the bank account cannot withdraw the amount
+        SpendWithdrawalBlocked(BankId, SpendId, DispatchError),
+        // bank, spend, fee taken from the spend amount, fee destination
+        SpendFeeCharged(BankId, SpendId, Balance, AccountId),
+        SpendVetoed(AccountId, BankId, SpendId),
+        // processor, bank, proposal, reward paid (zero if the bank could not cover it)
+        SpendProcessedForReward(AccountId, BankId, SpendId, Balance),
+        MemberProposalProcessedForReward(AccountId, BankId, MemId, Balance),
+        BankFrozen(BankId, AccountId),
+        BankUnfrozen(BankId, AccountId),
+        // applicant, bank, request, tribute escrowed, shares requested
+        MembershipRequested(AccountId, BankId, MemId, Balance, Shares),
+        // sponsor, bank, request, membership proposal it became
+        MembershipRequestSponsored(AccountId, BankId, MemId, MemId),
+        // applicant, bank, request, tribute released
+        MembershipRequestWithdrawn(AccountId, BankId, MemId, Balance),
     }
 );
 
 decl_error! {
     pub enum Error for Module<T: Trait> {
-        LimitOfOneMolochPerOrg,
         InsufficientBalanceToFundBankOpen,
         CannotOpenBankAccountIfDepositIsBelowModuleMinimum,
         CannotOpenBankAccountForOrgIfBankCountExceedsLimitPerOrg,
         CannotCloseBankThatDNE,
+        CannotFundBankThatDNE,
+        MustBeMemberToFundBank,
         NotPermittedToOpenBankAccountForOrg,
         NotPermittedToTriggerVoteForBankAccount,
         NotPermittedToSudoApproveForBankAccount,
         NotPermittedToPollProposalForBankAccount,
         CannotSpendIfBankDNE,
         MustBeOrgSupervisorToCloseBankAccount,
+        CannotCloseBankWithLiveProposals,
+        CannotConfigureBankThatDNE,
+        NotPermittedToConfigureBankAccount,
         // shared proposal errs
         CannotProposeIfBankDNE,
         BankMustExistToProposeFrom,
@@ -207,23 +481,103 @@ decl_error! {
         CannotTriggerVoteIfProposalDNE,
         MustBeMemberToSponsorProposal,
         // spend proposal errs
+        SpendDestinationNotAllowlisted,
+        InsufficientBalanceToReserveSpendBond,
+        SpendBelowMinimum,
+        SpendExceedsBankBalance,
+        CannotAffordProposalFee,
+        InsufficientBalanceToReserveSponsorBond,
+        InsufficientBalanceToReserveTribute,
+        ReproposalTooSoon,
         CannotTriggerVoteFromCurrentSpendProposalState,
+        BankFundsAlreadyReservedByLiveSpends,
+        BankFrozen,
+        NotPermittedToFreezeBank,
+        CannotProcessProposalIfBankDNE,
+        CannotProcessProposalThatDNE,
+        MustBeMemberToProcessProposal,
+        CannotProcessProposalWithoutLiveVote,
+        CannotProcessProposalBeforeVoteConcludes,
         CannotSudoApproveSpendProposalIfBaseBankDNE,
         CannotSudoApproveSpendProposalIfSpendProposalDNE,
         CannotApproveAlreadyApprovedSpendProposal,
         CannotPollProposalIfBaseBankDNE,
         CannotPollProposalIfProposalDNE,
+        CannotPollProposalIfVoteDNE,
         // moloch member errs
+        MembershipWouldExceedMaxTotalShares,
+        MembershipWouldExceedMaxSharesPerMember,
+        CannotClaimLootIfBankDNE,
+        InsufficientLootToClaim,
+        NoDissolutionProceedsToClaim,
+        CannotDistributeToOrgThatDNE,
+        BankCannotCoverLootClaim,
+        BankNameTooLong,
+        TooManyAutomatedSpends,
         CannotTriggerVoteFromCurrentMemberProposalState,
+        CannotSponsorRequestThatDNE,
+        ApplicantLacksEscrowedTribute,
+        CannotWithdrawRequestThatDNE,
+        NotPermittedToWithdrawRequest,
+        CannotKickAccountThatIsNotMember,
+        CannotTriggerVoteFromCurrentKickProposalState,
         CannotBurnSharesIfBaseBankDNE,
+        InsufficientSharesToRagequit,
+        CannotCancelSpendProposalThatDNE,
+        NotPermittedToCancelSpendProposal,
+        CannotCancelSpendProposalOnceVoteTriggered,
+        CannotRetrySpendExecutionIfBankDNE,
+        CannotRetrySpendExecutionIfProposalDNE,
+        MustBeMemberToRetrySpendExecution,
+        CannotRetrySpendExecutionIfNotApprovedButNotExecuted,
+        CannotRagequitWithVoteInFlight,
         // for getting banks for org
         NoBanksForOrg,
         ThresholdCannotBeSetForOrg,
+        ThresholdMustRequireSupport,
+        MemberThresholdOverrideTooWeak,
+        PollFrequencyMustBeNonZero,
+        SplitSpendMustHavePayouts,
+        RecurringSpendMustHavePeriods,
+        CannotRetryRecurringSpend,
+        CannotVetoSpendThatDNE,
+        CannotVetoSpendOutsideGracePeriod,
+        SpendCapWindowMustBeNonZero,
+        TooManySplitLegs,
+        TooManyOpenProposals,
+        CannotDelegateForBankThatDNE,
+        MustBeMemberToDelegate,
+        DelegateMustBeMemberOrObserver,
+        DelegationWouldFormCycle,
+        CannotSpendToBankThatDNE,
+        CannotSpendToSameBank,
+        TributeBelowMinimumPerShare,
+        CannotTriggerVoteWithNoMembers,
+        BatchSummonMustBeNonEmpty,
+        TooManyBanksInBatchSummon,
+        CannotExecuteSpendThatDNE,
+        CannotExecuteResolvedSpend,
+        SpendConditionNotMet,
+        SpendWouldExceedSpendCap,
+        InsufficientBankBalanceToExecuteSpend,
+        SharesLockedUntilVested,
+        SharesLockedByConvictionVote,
+        CannotMergeBankIntoItself,
+        CannotMergeBanksOfDifferentOrgs,
+        CannotMergeBankWithLiveProposals,
+        CannotAmendSpendProposalThatDNE,
+        NotPermittedToAmendSpendProposal,
+        CannotAmendSpendProposalOnceVoteTriggered,
+        SpendAmendmentMustChangeAmountOrDest,
+        CannotAmendSpendWithCustomPayout,
+        OrgDoesNotExist,
+        TributeMustBeEscrowedByApplicant,
+        BankSizeWitnessTooLow,
     }
 }
 
 decl_storage! {
-    trait Store for Module<T: Trait> as Bank {
+    trait Store for Module<T: Trait> as Moloch {
         /// Counter for generating unique bank identifiers
         BankIdNonce get(fn bank_id_nonce): T::BankId;
 
@@ -235,11 +589,15 @@ decl_storage! {
         ProposalNonceMap get(fn proposal_nonce_map): map
             hasher(blake2_128_concat) T::BankId => T::MemId;
 
+        /// Counter for generating unique membership request identifiers
+        RequestNonceMap get(fn request_nonce_map): map
+            hasher(blake2_128_concat) T::BankId => T::MemId;
+
         /// Total number of banks registered in this module
         pub TotalBankCount get(fn total_bank_count): u32;
-        /// Hashset of orgs that have bank accounts
-        pub OrgBankRegistrar get(fn org_bank_registrar): map
-            hasher(blake2_128_concat) T::OrgId => Option<()>;
+        /// Number of open banks for each org, capped by `MaxBanksPerOrg`
+        pub OrgBankCount get(fn org_bank_count): map
+            hasher(blake2_128_concat) T::OrgId => u32;
 
         /// The store for organizational bank accounts
         pub BankStores get(fn bank_stores): map
@@ -254,11 +612,320 @@ decl_storage! {
         pub MemberProps get(fn member_props): double_map
             hasher(blake2_128_concat) T::BankId,
             hasher(blake2_128_concat) T::MemId => Option<MemberProp<T>>;
+        /// Proposals to kick a member, sharing ids with membership proposals
+        pub KickProps get(fn kick_props): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<KickProp<T>>;
+        /// Spend proposals that polling may still move along, so polling
+        /// scales with live proposals rather than all proposals ever made
+        pub ActiveSpendProps get(fn active_spend_props): map
+            hasher(blake2_128_concat) T::BankId => Vec<T::SpendId>;
+        /// Membership proposals that polling may still move along
+        pub ActiveMemberProps get(fn active_member_props): map
+            hasher(blake2_128_concat) T::BankId => Vec<T::MemId>;
+        /// Kick proposals that polling may still move along
+        pub ActiveKickProps get(fn active_kick_props): map
+            hasher(blake2_128_concat) T::BankId => Vec<T::MemId>;
+        /// Active spend proposals left to poll in the current pass
+        pub SpendPollQueue get(fn spend_poll_queue): Vec<(T::BankId, T::SpendId)>;
+        /// Active membership proposals left to poll in the current pass
+        pub MemberPollQueue get(fn member_poll_queue): Vec<(T::BankId, T::MemId)>;
+        /// Active kick proposals left to poll in the current pass
+        pub KickPollQueue get(fn kick_poll_queue): Vec<(T::BankId, T::MemId)>;
+        /// Banks whose active spend proposals are queued at each block
+        pub SpendPollsDue get(fn spend_polls_due): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::BankId>;
+        /// Banks whose active membership and kick proposals are queued at each block
+        pub MemberPollsDue get(fn member_polls_due): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::BankId>;
+        /// Spend proposals sitting in the spend poll queue
+        SpendQueuedForPoll: double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => bool;
+        /// Membership proposals sitting in the membership poll queue
+        MemberQueuedForPoll: double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => bool;
+        /// Kick proposals sitting in the kick poll queue
+        KickQueuedForPoll: double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => bool;
+
+        /// Banks frozen in an emergency, which may not propose, vote on or approve spends
+        pub FrozenBanks get(fn frozen_banks): map
+            hasher(blake2_128_concat) T::BankId => bool;
+        /// Banks for which spend destinations must be allowlisted (default off)
+        pub RequireDestAllowlist get(fn require_dest_allowlist): map
+            hasher(blake2_128_concat) T::BankId => bool;
+        /// Destinations approved to receive spends from the bank
+        pub DestAllowlist get(fn dest_allowlist): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Option<()>;
+
+        /// Non-members who may read the bank's detailed proposal metadata
+        pub Observers get(fn observers): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Option<()>;
+
+        /// Final (in favor, against) tally of the last vote concluded on each spend proposal
+        pub SpendTallies get(fn spend_proposal_tally): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<(T::Signal, T::Signal)>;
+        /// Final (in favor, against) tally of the last vote concluded on each membership proposal
+        pub MemberTallies get(fn member_proposal_tally): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<(T::Signal, T::Signal)>;
+
+        /// Spend and membership proposals each member has open in the bank
+        pub OpenProposalCount get(fn open_proposal_count): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => u32;
+        /// Member each open spend proposal is counted against, until it is deactivated
+        SpendOpenedBy get(fn spend_opened_by): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::AccountId>;
+        /// Member each open membership or kick proposal is counted against, until it is deactivated
+        MemberOpenedBy get(fn member_opened_by): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<T::AccountId>;
+
+        /// Account each member has authorized to trigger votes on their behalf
+        pub Delegates get(fn delegates): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::AccountId>;
+        /// Members who have delegated to each account, for checking a delegate's rights
+        pub Delegators get(fn delegators): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Vec<T::AccountId>;
+
+        /// Threshold for spend votes, overriding the bank's threshold when set
+        pub SpendThreshold get(fn spend_threshold): map
+            hasher(blake2_128_concat) T::BankId => Option<T::ThresholdId>;
+        /// Threshold for membership votes, overriding the bank's threshold when set
+        pub MemberThreshold get(fn member_threshold): map
+            hasher(blake2_128_concat) T::BankId => Option<T::ThresholdId>;
+
+        /// Minimum turnout required for an approved vote to pass, as a portion of possible turnout
+        pub BankQuorum get(fn bank_quorum): map
+            hasher(blake2_128_concat) T::BankId => Option<Permill>;
+
+        /// Bonds reserved by spend proposers for live spend proposals
+        pub SpendBonds get(fn spend_bonds): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<(T::AccountId, BalanceOf<T>)>;
+        /// Block after which a spend proposal without a live vote expires
+        pub SpendExpiry get(fn spend_expiry): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::BlockNumber>;
+        /// Block after which a membership proposal without a live vote expires
+        pub MemberExpiry get(fn member_expiry): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<T::BlockNumber>;
+        /// Block after which a kick proposal without a live vote expires
+        pub KickExpiry get(fn kick_expiry): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<T::BlockNumber>;
+        /// Contribution history of each account in the bank
+        pub MemberStats get(fn member_stats): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => MemberStat<BalanceOf<T>>;
+
+        /// Blocks a proposal may stay in `Voting` before it is force resolved
+        pub MaxVoteAge get(fn max_vote_age): map
+            hasher(blake2_128_concat) T::BankId => Option<T::BlockNumber>;
+        /// Block at which each live proposal vote was triggered
+        pub VoteStarted get(fn vote_started): map
+            hasher(blake2_128_concat) T::VoteId => Option<T::BlockNumber>;
+
+        /// Non-refundable fee paid into the bank for each proposal (default zero)
+        pub ProposalFee get(fn proposal_fee): map
+            hasher(blake2_128_concat) T::BankId => BalanceOf<T>;
+        /// Bond reserved from the member sponsoring a membership proposal (default zero)
+        pub SponsorBond get(fn sponsor_bond): map
+            hasher(blake2_128_concat) T::BankId => BalanceOf<T>;
+        /// Where rejected spend bonds are sent for the bank (defaults to the bank)
+        pub SlashedBondDest get(fn slashed_bond_dest): map
+            hasher(blake2_128_concat) T::BankId => SlashedBondDestination;
+
+        /// Portion of a ragequitting member's claim retained by the bank (default zero)
+        pub RagequitFee get(fn ragequit_fee): map
+            hasher(blake2_128_concat) T::BankId => Permill;
+        /// Portion of requested shares minted as a bonus to approved applicants (default zero)
+        pub TributeMatch get(fn tribute_match): map
+            hasher(blake2_128_concat) T::BankId => Permill;
+        /// Minimum tribute per requested share for membership proposals (unenforced if unset)
+        pub MinTributePerShare get(fn min_tribute_per_share): map
+            hasher(blake2_128_concat) T::BankId => Option<BalanceOf<T>>;
+        /// Spend votes accept conviction, amplifying voters who lock their shares
+        pub ConvictionEnabled get(fn conviction_enabled): map
+            hasher(blake2_128_concat) T::BankId => bool;
+
+        /// Where the residual left after the close distribution goes (defaults to the closer)
+        pub CloseDustDest get(fn close_dust_dest): map
+            hasher(blake2_128_concat) T::BankId => CloseDustDestination;
+
+        /// Non-voting economic shares held in the bank
+        pub Loot get(fn loot): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => T::Shares;
+        /// Total loot issued by the bank
+        pub TotalLoot get(fn total_loot): map
+            hasher(blake2_128_concat) T::BankId => T::Shares;
+        /// Close distribution owed to members whose transfer failed, held in the closed
+        /// bank's account until claimed (kept after the bank is unregistered)
+        pub UnclaimedDissolution get(fn unclaimed_dissolution): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
+
+        /// Threshold a membership proposal is voted on with in place of the bank's
+        pub MemberThresholdOverrides get(fn member_threshold_overrides): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<T::ThresholdId>;
+        /// Applicant-signed requests to join with escrowed tribute, awaiting a member sponsor
+        pub MembershipRequests get(fn membership_requests): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<(T::AccountId, BalanceOf<T>, T::Shares)>;
+
+        /// Cap on the total shares of the bank's org that membership proposals may mint up to
+        pub MaxTotalShares get(fn max_total_shares): map
+            hasher(blake2_128_concat) T::BankId => Option<T::Shares>;
+        /// Per-bank override of the cap on shares held by a single member
+        pub BankMaxSharesPerMember get(fn bank_max_shares_per_member): map
+            hasher(blake2_128_concat) T::BankId => Option<T::Shares>;
+
+        /// Shares minted by membership proposals and the block they unlock at
+        pub LockedShares get(fn locked_shares): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::AccountId => Option<(T::Shares, T::BlockNumber)>;
+
+        /// Recent activity of each bank with the block it happened in, oldest first
+        /// and trimmed to `MaxActivityLog`. Kept after the bank closes.
+        pub BankActivityLog get(fn bank_activity): map
+            hasher(blake2_128_concat) T::BankId => Vec<(T::BlockNumber, Activity<T>)>;
+
+        /// Conditions that must hold for approved spends to execute
+        pub SpendConditions get(fn spend_conditions): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<SpendCond<T>>;
+        /// Hash of the off-chain document describing a spend proposal, set once when proposed
+        pub SpendMetadata get(fn spend_metadata): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::Hash>;
+        /// Hash of the off-chain document describing a membership proposal, set once when proposed
+        pub MemberMetadata get(fn member_metadata): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::MemId => Option<T::Hash>;
+        /// Bank funds reserved for spends in a live vote, released when the vote concludes
+        pub SpendReservations get(fn spend_reservations): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<BalanceOf<T>>;
+        /// Payouts of split spends that are yet to be transferred, failed legs remain after execution
+        pub SplitPayouts get(fn split_payouts): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<Vec<(T::AccountId, BalanceOf<T>)>>;
+        /// Cap on the spends a bank executes within each window of blocks
+        pub SpendCapPerWindow get(fn spend_cap_per_window): map
+            hasher(blake2_128_concat) T::BankId => Option<(BalanceOf<T>, T::BlockNumber)>;
+        /// Start of the bank's current cap window and the spends executed within it
+        pub SpendWindowUsage get(fn spend_window_usage): map
+            hasher(blake2_128_concat) T::BankId => (T::BlockNumber, BalanceOf<T>);
+        /// Approved spends deferred by the spend cap, retried by polling
+        pub CapDeferredSpends get(fn cap_deferred_spends): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => bool;
+        /// Fees charged on a spend's payouts, the proposal keeping the gross amount
+        pub SpendFeesCharged get(fn spend_fee_charged): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<BalanceOf<T>>;
+        /// Block at which the grace period of an approved spend ends
+        pub SpendGraceEnds get(fn spend_grace_ends): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::BlockNumber>;
+        /// Spends that distribute their amount to the bank's holders instead of a payee
+        pub DividendSpends get(fn dividend_spends): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => bool;
+        /// Receiving bank of spends that pay into another bank's account
+        pub InterBankSpends get(fn inter_bank_spends): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<T::BankId>;
+        /// Schedules of recurring spends, removed after the final installment
+        pub RecurringSpends get(fn recurring_spends): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(blake2_128_concat) T::SpendId => Option<RecurringSched<T>>;
+        /// Recurring spends with an installment due at each block
+        pub RecurringPayoutsDue get(fn recurring_payouts_due): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<(T::BankId, T::SpendId)>;
+        /// Number of conditional spends in the bank that are neither executed nor rejected
+        pub AutomatedSpendCount get(fn automated_spend_count): map
+            hasher(blake2_128_concat) T::BankId => u32;
+
+        /// Blocks after a rejection before an identical proposal may be made again (default zero)
+        pub ReproposalCooloff get(fn reproposal_cooloff): map
+            hasher(blake2_128_concat) T::BankId => T::BlockNumber;
+        /// Block from which a rejected proposal may be re-proposed, keyed by its salient fields hash
+        pub RecentRejections get(fn recent_rejections): double_map
+            hasher(blake2_128_concat) T::BankId,
+            hasher(identity) T::Hash => Option<T::BlockNumber>;
+        /// Rejections to clear at each block, checked against the block recorded for them
+        RejectionExpiries: map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<(T::BankId, T::Hash)>;
 
         /// Frequency for which all spend proposals are polled and pushed along
         SpendPollFrequency get(fn spend_poll_frequency) config(): T::BlockNumber;
         /// Frequency for which all membership proposals are polled and pushed along
         MemberPollFrequency get(fn member_poll_frequency) config(): T::BlockNumber;
+        /// Per-bank override of the spend poll frequency
+        pub BankSpendPollFrequency get(fn bank_spend_poll_frequency): map
+            hasher(blake2_128_concat) T::BankId => Option<T::BlockNumber>;
+        /// Per-bank override of the membership poll frequency
+        pub BankMemberPollFrequency get(fn bank_member_poll_frequency): map
+            hasher(blake2_128_concat) T::BankId => Option<T::BlockNumber>;
+    }
+    add_extra_genesis {
+        /// Banks opened at genesis as (opener, org, deposit, controller, passage threshold),
+        /// assigned bank identifiers in order starting from 1
+        config(banks): Vec<(T::AccountId, T::OrgId, BalanceOf<T>, Option<T::AccountId>, Permill)>;
+        /// Shares minted at genesis in the org of a genesis bank
+        config(initial_members): Vec<(T::BankId, T::AccountId, T::Shares)>;
+        /// Spend proposals awaiting approval at genesis as (bank, proposer, amount, dest)
+        config(spends): Vec<(T::BankId, T::AccountId, BalanceOf<T>, T::AccountId)>;
+
+        build(|config: &GenesisConfig<T>| {
+            assert!(
+                !config.spend_poll_frequency.is_zero()
+                    && !config.member_poll_frequency.is_zero(),
+                "poll frequencies must be non-zero"
+            );
+            for (opener, org, deposit, controller, passage) in config.banks.iter() {
+                assert!(
+                    <OrgBankCount<T>>::get(org) < T::MaxBanksPerOrg::get(),
+                    "genesis banks exceed the limit per org"
+                );
+                let threshold = ThresholdInput::new(
+                    OrgRep::Weighted(*org),
+                    XorThreshold::Percent(VoteThreshold::new(*passage, None)),
+                );
+                <Module<T>>::open_bank_account(
+                    opener.clone(),
+                    *org,
+                    *deposit,
+                    controller.clone(),
+                    threshold,
+                ).expect("genesis bank set up failed");
+                <OrgBankCount<T>>::mutate(org, |count| *count += 1);
+            }
+            for (bank_id, who, shares) in config.initial_members.iter() {
+                let bank = <BankStores<T>>::get(bank_id)
+                    .expect("genesis members must join a genesis bank");
+                <org::Module<T>>::issue(bank.org(), who.clone(), *shares, false)
+                    .expect("genesis share issuance failed");
+            }
+            for (bank_id, proposer, amount, dest) in config.spends.iter() {
+                <Module<T>>::_propose_spend(proposer, *bank_id, *amount, dest.clone())
+                    .expect("genesis spend proposal failed");
+            }
+        })
     }
 }
 
@@ -267,51 +934,317 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
-        #[weight = 0]
+        #[weight = T::WeightInfo::summon()]
         fn summon(
             origin,
             org: T::OrgId,
             deposit: BalanceOf<T>,
             controller: Option<T::AccountId>,
             threshold: Threshold<T>,
+            name: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let opener = ensure_signed(origin)?;
+            Self::summon_bank(&opener, org, deposit, controller, threshold, name)?;
+            Ok(())
+        }
+        /// Opens a bank per spec in order. Unless `all_or_nothing`, stops at the first
+        /// failure and keeps the banks opened before it.
+        #[weight = T::WeightInfo::batch_summon(specs.len() as u32)]
+        fn batch_summon(
+            origin,
+            specs: Vec<(T::OrgId, BalanceOf<T>, Option<T::AccountId>, Threshold<T>)>,
+            all_or_nothing: bool,
         ) -> DispatchResult {
-            ensure!(<OrgBankRegistrar<T>>::get(org).is_none(), Error::<T>::LimitOfOneMolochPerOrg);
             let opener = ensure_signed(origin)?;
+            ensure!(!specs.is_empty(), Error::<T>::BatchSummonMustBeNonEmpty);
+            ensure!(
+                specs.len() as u32 <= T::MaxBatchSummon::get(),
+                Error::<T>::TooManyBanksInBatchSummon
+            );
+            let total = specs.len() as u32;
+            let mut opened = Vec::new();
+            if all_or_nothing {
+                with_transaction(|| {
+                    for (org, deposit, controller, threshold) in specs {
+                        match Self::summon_bank(&opener, org, deposit, controller, threshold, None) {
+                            Ok(bank_id) => opened.push(bank_id),
+                            Err(e) => return TransactionOutcome::Rollback(Err(e)),
+                        }
+                    }
+                    TransactionOutcome::Commit(Ok(()))
+                })?;
+            } else {
+                for (org, deposit, controller, threshold) in specs {
+                    match Self::summon_bank(&opener, org, deposit, controller, threshold, None) {
+                        Ok(bank_id) => opened.push(bank_id),
+                        Err(_) => break,
+                    }
+                }
+            }
+            Self::deposit_event(RawEvent::BanksBatchSummoned(opener, opened, total));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::member_funds_bank()]
+        fn member_funds_bank(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <BankStores<T>>::get(bank_id)
+                .ok_or(Error::<T>::CannotFundBankThatDNE)?;
             ensure!(
-                <org::Module<T>>::is_member_of_group(org, &opener),
-                Error::<T>::NotPermittedToOpenBankAccountForOrg
+                <org::Module<T>>::is_member_of_group(bank.org(), &caller),
+                Error::<T>::MustBeMemberToFundBank
             );
-            let bank_id = Self::open_bank_account(opener.clone(), org, deposit, controller.clone(), threshold)?;
-            <OrgBankRegistrar<T>>::insert(org, ());
-            Self::deposit_event(RawEvent::AccountOpened(opener, bank_id, deposit, org, controller));
+            <T as Trait>::Currency::transfer(
+                &caller,
+                &Self::bank_account_id(bank_id),
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            Self::deposit_event(RawEvent::BankFunded(caller, bank_id, amount));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::propose_spend()]
         fn propose_spend(
             origin,
             bank_id: T::BankId,
             amount: BalanceOf<T>,
             dest: T::AccountId,
+            metadata: Option<T::Hash>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, dest.clone())?;
+            if let Some(hash) = metadata {
+                <SpendMetadata<T>>::insert(bank_id, new_spend_id, hash);
+            }
+            let fee = <ProposalFee<T>>::get(bank_id);
+            Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest, fee, metadata));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::member_proposes_conditional_spend()]
+        fn member_proposes_conditional_spend(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+            dest: T::AccountId,
+            condition: SpendCond<T>,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
+            ensure!(
+                <AutomatedSpendCount<T>>::get(bank_id) < T::MaxAutomatedSpendsPerBank::get(),
+                Error::<T>::TooManyAutomatedSpends
+            );
             let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, dest.clone())?;
-            Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest));
+            <SpendConditions<T>>::insert(bank_id, new_spend_id, condition);
+            <AutomatedSpendCount<T>>::mutate(bank_id, |count| *count += 1);
+            let fee = <ProposalFee<T>>::get(bank_id);
+            Self::deposit_event(RawEvent::SpendProposed(caller, bank_id, new_spend_id, amount, dest, fee, None));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::member_proposes_split_spend(payouts.len() as u32)]
+        fn member_proposes_split_spend(
+            origin,
+            bank_id: T::BankId,
+            payouts: Vec<(T::AccountId, BalanceOf<T>)>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(!payouts.is_empty(), Error::<T>::SplitSpendMustHavePayouts);
+            let legs = payouts.len() as u32;
+            ensure!(legs <= T::MaxSplitLegs::get(), Error::<T>::TooManySplitLegs);
+            ensure!(
+                payouts.iter().all(|(dest, _)| Self::is_allowlisted_dest(bank_id, dest)),
+                Error::<T>::SpendDestinationNotAllowlisted
+            );
+            let total = payouts
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |acc, (_, amt)| acc.saturating_add(*amt));
+            // the proposal records the first payee, the legs are paid out from `SplitPayouts`
+            let new_spend_id = Self::_propose_spend(&caller, bank_id, total, payouts[0].0.clone())?;
+            <SplitPayouts<T>>::insert(bank_id, new_spend_id, payouts);
+            Self::deposit_event(RawEvent::SplitSpendProposed(caller, bank_id, new_spend_id, total, legs));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_spend()]
+        fn member_proposes_dividend(
+            origin,
+            bank_id: T::BankId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            // the proposal pays the bank itself, holders are paid their portion on execution
+            let new_spend_id = Self::_propose_spend(&caller, bank_id, amount, Self::bank_account_id(bank_id))?;
+            <DividendSpends<T>>::insert(bank_id, new_spend_id, true);
+            Self::deposit_event(RawEvent::DividendProposed(caller, bank_id, new_spend_id, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_spend()]
+        fn member_proposes_spend_to_bank(
+            origin,
+            from_bank: T::BankId,
+            to_bank: T::BankId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(Self::is_bank(to_bank), Error::<T>::CannotSpendToBankThatDNE);
+            ensure!(from_bank != to_bank, Error::<T>::CannotSpendToSameBank);
+            let new_spend_id = Self::_propose_spend(&caller, from_bank, amount, Self::bank_account_id(to_bank))?;
+            <InterBankSpends<T>>::insert(from_bank, new_spend_id, to_bank);
+            Self::deposit_event(RawEvent::InterBankSpendProposed(caller, from_bank, new_spend_id, to_bank, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::member_proposes_recurring_spend()]
+        fn member_proposes_recurring_spend(
+            origin,
+            bank_id: T::BankId,
+            amount_per_period: BalanceOf<T>,
+            period: T::BlockNumber,
+            num_periods: u32,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(
+                !period.is_zero() && num_periods > 0,
+                Error::<T>::RecurringSpendMustHavePeriods
+            );
+            // the proposal records one installment, the rest are paid out from `RecurringSpends`
+            let new_spend_id = Self::_propose_spend(&caller, bank_id, amount_per_period, dest)?;
+            <RecurringSpends<T>>::insert(bank_id, new_spend_id, RecurringSchedule {
+                amount_per_period,
+                period,
+                num_periods,
+                remaining: num_periods,
+                next_payout: None,
+            });
+            Self::deposit_event(RawEvent::RecurringSpendProposed(
+                caller, bank_id, new_spend_id, amount_per_period, period, num_periods,
+            ));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::member_cancels_spend()]
+        fn member_cancels_spend(
+            origin,
+            bank_id: T::BankId,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let prop = <SpendProps<T>>::get(bank_id, spend_id)
+                .ok_or(Error::<T>::CannotCancelSpendProposalThatDNE)?;
+            ensure!(
+                prop.state() == SpendState::WaitingForApproval,
+                Error::<T>::CannotCancelSpendProposalOnceVoteTriggered
+            );
+            ensure!(
+                prop.proposer() == caller,
+                Error::<T>::NotPermittedToCancelSpendProposal
+            );
+            <SpendProps<T>>::remove(bank_id, spend_id);
+            <SpendExpiry<T>>::remove(bank_id, spend_id);
+            <SpendMetadata<T>>::remove(bank_id, spend_id);
+            Self::clear_spend_side_tables(bank_id, spend_id);
+            Self::return_spend_bond(bank_id, spend_id);
+            Self::deactivate_spend(bank_id, spend_id);
+            Self::deposit_event(RawEvent::SpendProposalCancelled(caller, bank_id, spend_id));
+            Ok(())
+        }
+        /// Lets the proposer correct the amount or destination of a spend before any
+        /// vote on it is triggered, keeping its id and history
+        #[weight = T::WeightInfo::propose_spend()]
+        fn amend_spend_proposal(
+            origin,
+            bank_id: T::BankId,
+            spend_id: T::SpendId,
+            new_amount: Option<BalanceOf<T>>,
+            new_dest: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::_amend_spend_proposal(&caller, bank_id, spend_id, new_amount, new_dest)?;
+            Self::deposit_event(RawEvent::SpendProposalAmended(bank_id, spend_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_member()]
         fn propose_member(
             origin,
             bank_id: T::BankId,
             tribute: BalanceOf<T>,
             shares_requested: T::Shares,
+            loot_requested: T::Shares,
             applicant: T::AccountId,
+            metadata: Option<T::Hash>,
+            threshold_override: Option<Threshold<T>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            if let Some(threshold) = &threshold_override {
+                Self::ensure_member_threshold_override(bank_id, threshold)?;
+            }
+            let proposal_id = Self::_propose_member(&caller, bank_id, tribute, shares_requested, loot_requested, applicant.clone())?;
+            if let Some(hash) = metadata {
+                <MemberMetadata<T>>::insert(bank_id, proposal_id, hash);
+            }
+            if let Some(threshold) = threshold_override {
+                let threshold_id = T::VoteMachine::register_threshold(threshold)?;
+                <MemberThresholdOverrides<T>>::insert(bank_id, proposal_id, threshold_id);
+            }
+            let fee = <ProposalFee<T>>::get(bank_id);
+            Self::deposit_event(RawEvent::MemberProposed(caller, bank_id, proposal_id, tribute, shares_requested, loot_requested, applicant, fee, metadata));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::submit_membership_request()]
+        fn applicant_submits_membership_request(
+            origin,
+            bank_id: T::BankId,
+            tribute: BalanceOf<T>,
+            shares_requested: T::Shares,
+        ) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+            ensure!(Self::is_bank(bank_id), Error::<T>::BankMustExistToProposeFrom);
+            // tribute is escrowed by the applicant's own signature
+            <T as Trait>::Currency::reserve(&applicant, tribute)
+                .map_err(|_| Error::<T>::InsufficientBalanceToReserveTribute)?;
+            let request_id = Self::generate_request_uid(bank_id);
+            <MembershipRequests<T>>::insert(bank_id, request_id, (applicant.clone(), tribute, shares_requested));
+            Self::deposit_event(RawEvent::MembershipRequested(applicant, bank_id, request_id, tribute, shares_requested));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_member()]
+        fn member_sponsors_request(
+            origin,
+            bank_id: T::BankId,
+            request_id: T::MemId,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
-            let proposal_id = Self::_propose_member(&caller, bank_id, tribute, shares_requested, applicant.clone())?;
-            Self::deposit_event(RawEvent::MemberProposed(caller, bank_id, proposal_id, tribute, shares_requested, applicant));
+            let proposal_id = Self::sponsor_request(&caller, bank_id, request_id)?;
+            Self::deposit_event(RawEvent::MembershipRequestSponsored(caller, bank_id, request_id, proposal_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::withdraw_membership_request()]
+        fn applicant_withdraws_request(
+            origin,
+            bank_id: T::BankId,
+            request_id: T::MemId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let (applicant, tribute, _) = <MembershipRequests<T>>::get(bank_id, request_id)
+                .ok_or(Error::<T>::CannotWithdrawRequestThatDNE)?;
+            ensure!(applicant == caller, Error::<T>::NotPermittedToWithdrawRequest);
+            <T as Trait>::Currency::unreserve(&applicant, tribute);
+            <MembershipRequests<T>>::remove(bank_id, request_id);
+            Self::deposit_event(RawEvent::MembershipRequestWithdrawn(applicant, bank_id, request_id, tribute));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::propose_guild_kick()]
+        fn propose_guild_kick(
+            origin,
+            bank_id: T::BankId,
+            target: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let kick_id = Self::_propose_guild_kick(&caller, bank_id, target.clone())?;
+            Self::deposit_event(RawEvent::GuildKickProposed(caller, bank_id, kick_id, target));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::trigger_vote()]
         fn spend_trigger_vote(
             origin,
             bank_id: T::BankId,
@@ -319,10 +1252,11 @@ decl_module! {
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let vote_id = Self::_trigger_vote_on_spend_proposal(&caller, bank_id, spend_id)?;
+            Self::log_activity(bank_id, BankActivity::SpendVoteTriggered(spend_id, vote_id));
             Self::deposit_event(RawEvent::SpendVoteTriggered(caller, bank_id, spend_id, vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::trigger_vote()]
         fn member_trigger_vote(
             origin,
             bank_id: T::BankId,
@@ -330,10 +1264,22 @@ decl_module! {
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
             let new_vote_id = Self::_trigger_vote_on_member_proposal(&caller, bank_id, proposal_id)?;
+            Self::log_activity(bank_id, BankActivity::MemberVoteTriggered(proposal_id, new_vote_id));
             Self::deposit_event(RawEvent::MemberVoteTriggered(caller, bank_id, proposal_id, new_vote_id));
             Ok(())
         }
-        #[weight = 0]
+        #[weight = T::WeightInfo::trigger_vote()]
+        fn kick_trigger_vote(
+            origin,
+            bank_id: T::BankId,
+            kick_id: T::MemId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let vote_id = Self::_trigger_vote_on_kick_proposal(&caller, bank_id, kick_id)?;
+            Self::deposit_event(RawEvent::KickVoteTriggered(caller, bank_id, kick_id, vote_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::sudo_approve_spend_proposal()]
         fn sudo_approve_spend_proposal(
             origin,
             bank_id: T::BankId,
@@ -344,141 +1290,3229 @@ decl_module! {
             Self::deposit_event(RawEvent::SpendSudoApproved(caller, bank_id, spend_id));
             Ok(())
         }
-        #[weight = 0]
-        fn burn_shares(
+        #[weight = T::WeightInfo::controller_vetoes_spend()]
+        fn controller_vetoes_spend(
             origin,
             bank_id: T::BankId,
+            spend_id: T::SpendId,
         ) -> DispatchResult {
             let caller = ensure_signed(origin)?;
-            Self::_burn_shares(caller, bank_id)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            let prop = <SpendProps<T>>::get(bank_id, spend_id)
+                .ok_or(Error::<T>::CannotVetoSpendThatDNE)?;
+            let vote_id = if let SpendState::GracePeriod(vote_id) = prop.state() {
+                vote_id
+            } else {
+                return Err(Error::<T>::CannotVetoSpendOutsideGracePeriod.into())
+            };
+            <SpendProps<T>>::insert(bank_id, spend_id, prop.set_state(SpendState::Rejected(vote_id)));
+            <SpendGraceEnds<T>>::remove(bank_id, spend_id);
+            Self::clear_spend_side_tables(bank_id, spend_id);
+            // the proposer was credited with a passed spend when it entered grace
+            <MemberStats<T>>::mutate(bank_id, prop.proposer(), |s| {
+                s.spends_passed = s.spends_passed.saturating_sub(1)
+            });
+            Self::record_rejection(bank_id, Self::spend_key(prop.amount(), &prop.dest()));
+            Self::deposit_event(RawEvent::SpendProposalPolled(
+                bank_id,
+                spend_id,
+                SpendState::Rejected(vote_id),
+                None,
+                ResolutionTrigger::Sudo(caller.clone()),
+            ));
+            Self::deposit_event(RawEvent::SpendVetoed(caller, bank_id, spend_id));
             Ok(())
         }
-        #[weight = 0]
-        fn close_org_bank_account(
+        #[weight = T::WeightInfo::process_proposal()]
+        fn member_processes_spend(
             origin,
             bank_id: T::BankId,
+            spend_id: T::SpendId,
         ) -> DispatchResult {
-            let closer = ensure_signed(origin)?;
-            let bank = <BankStores<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseBankThatDNE)?;
+            let caller = ensure_signed(origin)?;
+            Self::ensure_can_process(&caller, bank_id)?;
+            let prop = <SpendProps<T>>::get(bank_id, spend_id)
+                .ok_or(Error::<T>::CannotProcessProposalThatDNE)?;
             ensure!(
-                bank.is_controller(&closer),
-                Error::<T>::MustBeOrgSupervisorToCloseBankAccount
+                prop.state().is_voting(),
+                Error::<T>::CannotProcessProposalWithoutLiveVote
             );
-            let bank_account_id = Self::bank_account_id(bank_id);
-            let remaining_funds = <T as donate::Trait>::Currency::total_balance(&bank_account_id);
-            // distributes remaining funds equally among members in proportion to ownership (PropDonation)
-            let _ = <donate::Module<T>>::donate(
-                &bank_account_id,
-                OrgRep::Weighted(bank.org()),
-                &closer,
-                remaining_funds,
-            )?;
-            <BankStores<T>>::remove(bank_id);
-            <TotalBankCount>::mutate(|count| *count -= 1);
-            <OrgBankRegistrar<T>>::remove(bank.org());
-            Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
+            let (state, tally) = Self::poll_and_tally_spend(prop)?;
+            ensure!(
+                !state.is_voting(),
+                Error::<T>::CannotProcessProposalBeforeVoteConcludes
+            );
+            Self::deposit_event(RawEvent::SpendProposalPolled(
+                bank_id, spend_id, state, tally, ResolutionTrigger::Member(caller.clone()),
+            ));
+            if !Self::spend_is_live(bank_id, spend_id, state) {
+                Self::deactivate_spend(bank_id, spend_id);
+            }
+            let reward = Self::pay_processing_reward(bank_id, &caller);
+            Self::deposit_event(RawEvent::SpendProcessedForReward(caller, bank_id, spend_id, reward));
             Ok(())
         }
-        fn on_finalize(_n: T::BlockNumber) {
-            if <frame_system::Module<T>>::block_number() % Self::spend_poll_frequency() == Zero::zero() {
-                <SpendProps<T>>::iter().for_each(|(_, _, prop)| {
-                    let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
-                    if let Ok(state) = Self::poll_spend_proposal(prop) {
-                        Self::deposit_event(RawEvent::SpendProposalPolled(bank_id, spend_id, state));
-                    }
-                });
-            }
-            if <frame_system::Module<T>>::block_number() % Self::member_poll_frequency() == Zero::zero() {
-                <MemberProps<T>>::iter().for_each(|(_, _, prop)| {
-                    let (bank_id, prop_id) = (prop.bank_id(), prop.prop_id());
-                    if let Ok(state) = Self::poll_membership_proposal(prop) {
-                        Self::deposit_event(RawEvent::MemberProposalPolled(bank_id, prop_id, state));
-                    }
-                });
+        #[weight = T::WeightInfo::process_proposal()]
+        fn member_processes_member_proposal(
+            origin,
+            bank_id: T::BankId,
+            prop_id: T::MemId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_can_process(&caller, bank_id)?;
+            let prop = <MemberProps<T>>::get(bank_id, prop_id)
+                .ok_or(Error::<T>::CannotProcessProposalThatDNE)?;
+            ensure!(
+                prop.state().is_voting(),
+                Error::<T>::CannotProcessProposalWithoutLiveVote
+            );
+            let (state, tally) = Self::poll_and_tally_member(prop)?;
+            ensure!(
+                !state.is_voting(),
+                Error::<T>::CannotProcessProposalBeforeVoteConcludes
+            );
+            Self::deposit_event(RawEvent::MemberProposalPolled(
+                bank_id, prop_id, state, tally, ResolutionTrigger::Member(caller.clone()),
+            ));
+            if !Self::proposal_is_live(state) {
+                Self::deactivate_member(bank_id, prop_id);
             }
+            let reward = Self::pay_processing_reward(bank_id, &caller);
+            Self::deposit_event(RawEvent::MemberProposalProcessedForReward(caller, bank_id, prop_id, reward));
+            Ok(())
         }
+        #[weight = T::WeightInfo::member_retries_spend_execution()]
+        fn member_retries_spend_execution(
+            origin,
+            bank_id: T::BankId,
+            spend_id: T::SpendId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <BankStores<T>>::get(bank_id)
+                .ok_or(Error::<T>::CannotRetrySpendExecutionIfBankDNE)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(bank.org(), &caller),
+                Error::<T>::MustBeMemberToRetrySpendExecution
+            );
+            ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
+            let prop = <SpendProps<T>>::get(bank_id, spend_id)
+                .ok_or(Error::<T>::CannotRetrySpendExecutionIfProposalDNE)?;
+            ensure!(
+                prop.state() == SpendState::ApprovedButNotExecuted,
+                Error::<T>::CannotRetrySpendExecutionIfNotApprovedButNotExecuted
+            );
+            // installments are paid on schedule, a missed one is not retried
+            ensure!(
+                !<RecurringSpends<T>>::contains_key(bank_id, spend_id),
+                Error::<T>::CannotRetryRecurringSpend
+            );
+            let new_prop = Self::execute_spend(prop);
+            let executed = new_prop.state() == SpendState::ApprovedAndExecuted;
+            <SpendProps<T>>::insert(bank_id, spend_id, new_prop);
+            Self::deposit_event(RawEvent::SpendExecutionRetried(bank_id, spend_id, executed));
+            Ok(())
+        }
+        /// `live_spends` bounds the bank's active spend proposals checked for the caller's votes
+        #[weight = T::WeightInfo::ragequit(*live_spends)]
+        fn burn_shares(
+            origin,
+            bank_id: T::BankId,
+            live_spends: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_live_spends_witness(bank_id, live_spends)?;
+            Self::_burn_shares(caller, bank_id)?;
+            Ok(())
+        }
+        #[weight = T::WeightInfo::ragequit(*live_spends)]
+        fn member_ragequit(
+            origin,
+            bank_id: T::BankId,
+            shares_to_burn: T::Shares,
+            live_spends: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_live_spends_witness(bank_id, live_spends)?;
+            Self::ragequit(&caller, bank_id, Some(shares_to_burn))?;
+            Ok(())
+        }
+        #[weight = T::WeightInfo::claim_loot()]
+        fn claim_loot(
+            origin,
+            bank_id: T::BankId,
+            amount: T::Shares,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let payout = Self::_claim_loot(&caller, bank_id, amount)?;
+            Self::deposit_event(RawEvent::LootClaimed(bank_id, caller, amount, payout));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::claim_dissolution_proceeds()]
+        fn claim_dissolution_proceeds(
+            origin,
+            bank_id: T::BankId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let amount = <UnclaimedDissolution<T>>::get(bank_id, &caller);
+            ensure!(!amount.is_zero(), Error::<T>::NoDissolutionProceedsToClaim);
+            <T as Trait>::Currency::transfer(
+                &Self::bank_account_id(bank_id),
+                &caller,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            <UnclaimedDissolution<T>>::remove(bank_id, &caller);
+            Self::deposit_event(RawEvent::DissolutionProceedsClaimed(bank_id, caller, amount));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_require_dest_allowlist(
+            origin,
+            bank_id: T::BankId,
+            required: bool,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <RequireDestAllowlist<T>>::insert(bank_id, required);
+            Self::deposit_event(RawEvent::DestAllowlistRequired(bank_id, required));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn add_to_dest_allowlist(
+            origin,
+            bank_id: T::BankId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <DestAllowlist<T>>::insert(bank_id, &dest, ());
+            Self::deposit_event(RawEvent::DestAllowlisted(bank_id, dest));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn add_observer(
+            origin,
+            bank_id: T::BankId,
+            observer: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <Observers<T>>::insert(bank_id, &observer, ());
+            Self::deposit_event(RawEvent::ObserverAdded(bank_id, observer));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn remove_observer(
+            origin,
+            bank_id: T::BankId,
+            observer: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <Observers<T>>::remove(bank_id, &observer);
+            Self::deposit_event(RawEvent::ObserverRemoved(bank_id, observer));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::set_delegate()]
+        fn set_delegate(
+            origin,
+            bank_id: T::BankId,
+            delegate: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = <BankStores<T>>::get(bank_id)
+                .ok_or(Error::<T>::CannotDelegateForBankThatDNE)?;
+            ensure!(
+                <org::Module<T>>::is_member_of_group(bank.org(), &caller),
+                Error::<T>::MustBeMemberToDelegate
+            );
+            if let Some(d) = &delegate {
+                ensure!(
+                    Self::is_observer_or_member(bank_id, d),
+                    Error::<T>::DelegateMustBeMemberOrObserver
+                );
+                ensure!(
+                    !Self::delegation_reaches(bank_id, d, &caller),
+                    Error::<T>::DelegationWouldFormCycle
+                );
+            }
+            if let Some(old) = <Delegates<T>>::get(bank_id, &caller) {
+                <Delegators<T>>::mutate(bank_id, &old, |members| members.retain(|m| m != &caller));
+            }
+            if let Some(d) = &delegate {
+                <Delegates<T>>::insert(bank_id, &caller, d);
+                <Delegators<T>>::mutate(bank_id, d, |members| members.push(caller.clone()));
+            } else {
+                <Delegates<T>>::remove(bank_id, &caller);
+            }
+            Self::deposit_event(RawEvent::DelegateSet(bank_id, caller, delegate));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn remove_from_dest_allowlist(
+            origin,
+            bank_id: T::BankId,
+            dest: T::AccountId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <DestAllowlist<T>>::remove(bank_id, &dest);
+            Self::deposit_event(RawEvent::DestRemovedFromAllowlist(bank_id, dest));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_bank_quorum(
+            origin,
+            bank_id: T::BankId,
+            quorum: Option<Permill>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some(q) = quorum {
+                <BankQuorum<T>>::insert(bank_id, q);
+            } else {
+                <BankQuorum<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::BankQuorumSet(bank_id, quorum));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn rename_bank(
+            origin,
+            bank_id: T::BankId,
+            name: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = Self::ensure_controller(&caller, bank_id)?;
+            Self::ensure_bank_name(&name)?;
+            <BankStores<T>>::insert(bank_id, bank.set_name(name.clone()));
+            Self::deposit_event(RawEvent::BankRenamed(bank_id, name));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn transfer_bank_control(
+            origin,
+            bank_id: T::BankId,
+            new_controller: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = Self::ensure_controller(&caller, bank_id)?;
+            <BankStores<T>>::insert(bank_id, bank.set_controller(new_controller.clone()));
+            Self::deposit_event(RawEvent::BankControlTransferred(bank_id, Some(caller), new_controller));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::set_bank_thresholds()]
+        fn set_bank_thresholds(
+            origin,
+            bank_id: T::BankId,
+            spend: Option<Threshold<T>>,
+            member: Option<Threshold<T>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let bank = Self::ensure_controller(&caller, bank_id)?;
+            let spend_id = spend.map(|t| Self::register_bank_threshold(&bank, t)).transpose()?;
+            let member_id = member.map(|t| Self::register_bank_threshold(&bank, t)).transpose()?;
+            <SpendThreshold<T>>::mutate(bank_id, |t| *t = spend_id);
+            <MemberThreshold<T>>::mutate(bank_id, |t| *t = member_id);
+            Self::deposit_event(RawEvent::BankThresholdsSet(bank_id, spend_id, member_id));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_max_vote_age(
+            origin,
+            bank_id: T::BankId,
+            max_vote_age: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some(age) = max_vote_age {
+                <MaxVoteAge<T>>::insert(bank_id, age);
+            } else {
+                <MaxVoteAge<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::MaxVoteAgeSet(bank_id, max_vote_age));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_proposal_fee(
+            origin,
+            bank_id: T::BankId,
+            fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <ProposalFee<T>>::insert(bank_id, fee);
+            Self::deposit_event(RawEvent::ProposalFeeSet(bank_id, fee));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_sponsor_bond(
+            origin,
+            bank_id: T::BankId,
+            bond: BalanceOf<T>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <SponsorBond<T>>::insert(bank_id, bond);
+            Self::deposit_event(RawEvent::SponsorBondSet(bank_id, bond));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_slashed_bond_destination(
+            origin,
+            bank_id: T::BankId,
+            dest: SlashedBondDestination,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <SlashedBondDest<T>>::insert(bank_id, dest);
+            Self::deposit_event(RawEvent::SlashedBondDestinationSet(bank_id, dest));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_reproposal_cooloff(
+            origin,
+            bank_id: T::BankId,
+            cooloff: T::BlockNumber,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <ReproposalCooloff<T>>::insert(bank_id, cooloff);
+            Self::deposit_event(RawEvent::ReproposalCooloffSet(bank_id, cooloff));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_ragequit_fee(
+            origin,
+            bank_id: T::BankId,
+            fee: Permill,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <RagequitFee<T>>::insert(bank_id, fee);
+            Self::deposit_event(RawEvent::RagequitFeeSet(bank_id, fee));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_tribute_match(
+            origin,
+            bank_id: T::BankId,
+            tribute_match: Permill,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <TributeMatch<T>>::insert(bank_id, tribute_match);
+            Self::deposit_event(RawEvent::TributeMatchSet(bank_id, tribute_match));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_tribute_policy(
+            origin,
+            bank_id: T::BankId,
+            min_tribute_per_share: Option<BalanceOf<T>>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some(ratio) = min_tribute_per_share {
+                <MinTributePerShare<T>>::insert(bank_id, ratio);
+            } else {
+                <MinTributePerShare<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::TributePolicySet(bank_id, min_tribute_per_share));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_conviction_enabled(
+            origin,
+            bank_id: T::BankId,
+            enabled: bool,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if enabled {
+                <ConvictionEnabled<T>>::insert(bank_id, true);
+            } else {
+                <ConvictionEnabled<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::ConvictionEnabledSet(bank_id, enabled));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_close_dust_destination(
+            origin,
+            bank_id: T::BankId,
+            dest: CloseDustDestination,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            <CloseDustDest<T>>::insert(bank_id, dest);
+            Self::deposit_event(RawEvent::CloseDustDestinationSet(bank_id, dest));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_max_total_shares(
+            origin,
+            bank_id: T::BankId,
+            max_total_shares: Option<T::Shares>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some(cap) = max_total_shares {
+                <MaxTotalShares<T>>::insert(bank_id, cap);
+            } else {
+                <MaxTotalShares<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::MaxTotalSharesSet(bank_id, max_total_shares));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_spend_cap(
+            origin,
+            bank_id: T::BankId,
+            cap: Option<(BalanceOf<T>, T::BlockNumber)>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some((amount, window)) = cap {
+                ensure!(!window.is_zero(), Error::<T>::SpendCapWindowMustBeNonZero);
+                <SpendCapPerWindow<T>>::insert(bank_id, (amount, window));
+                // the first window starts now
+                <SpendWindowUsage<T>>::insert(
+                    bank_id,
+                    (<frame_system::Module<T>>::block_number(), BalanceOf::<T>::zero()),
+                );
+            } else {
+                <SpendCapPerWindow<T>>::remove(bank_id);
+                <SpendWindowUsage<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::SpendCapSet(bank_id, cap));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn set_max_shares_per_member(
+            origin,
+            bank_id: T::BankId,
+            max_shares: Option<T::Shares>,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            if let Some(cap) = max_shares {
+                <BankMaxSharesPerMember<T>>::insert(bank_id, cap);
+            } else {
+                <BankMaxSharesPerMember<T>>::remove(bank_id);
+            }
+            Self::deposit_event(RawEvent::MaxSharesPerMemberSet(bank_id, max_shares));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn freeze_bank(
+            origin,
+            bank_id: T::BankId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_can_freeze(&caller, bank_id)?;
+            <FrozenBanks<T>>::insert(bank_id, true);
+            Self::deposit_event(RawEvent::BankFrozen(bank_id, caller));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::configure_bank()]
+        fn unfreeze_bank(
+            origin,
+            bank_id: T::BankId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_can_freeze(&caller, bank_id)?;
+            <FrozenBanks<T>>::remove(bank_id);
+            Self::deposit_event(RawEvent::BankUnfrozen(bank_id, caller));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::set_poll_frequency()]
+        fn set_poll_frequency(
+            origin,
+            bank_id: T::BankId,
+            spend_freq: T::BlockNumber,
+            member_freq: T::BlockNumber,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_controller(&caller, bank_id)?;
+            ensure!(
+                !spend_freq.is_zero() && !member_freq.is_zero(),
+                Error::<T>::PollFrequencyMustBeNonZero
+            );
+            <BankSpendPollFrequency<T>>::insert(bank_id, spend_freq);
+            <BankMemberPollFrequency<T>>::insert(bank_id, member_freq);
+            // polls already scheduled under the old cadence reschedule themselves when they fire
+            let now = <frame_system::Module<T>>::block_number();
+            Self::schedule_spend_poll(bank_id, now);
+            Self::schedule_member_poll(bank_id, now);
+            Self::deposit_event(RawEvent::PollFrequencySet(bank_id, spend_freq, member_freq));
+            Ok(())
+        }
+        /// `members` bounds the holders paid out and `proposals` the proposals cleared
+        #[weight = T::WeightInfo::close_org_bank_account(*members, *proposals)]
+        fn close_org_bank_account(
+            origin,
+            bank_id: T::BankId,
+            force: bool,
+            members: u32,
+            proposals: u32,
+        ) -> DispatchResult {
+            let closer = ensure_signed(origin)?;
+            let bank = <BankStores<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseBankThatDNE)?;
+            ensure!(
+                bank.is_controller(&closer),
+                Error::<T>::MustBeOrgSupervisorToCloseBankAccount
+            );
+            Self::ensure_proposals_witness(bank_id, proposals)?;
+            // unless forced, every proposal must have resolved first
+            ensure!(
+                force || !Self::has_live_proposals(bank_id),
+                Error::<T>::CannotCloseBankWithLiveProposals
+            );
+            Self::dissolve_bank(bank_id, bank.org(), Some(&closer), members)?;
+            Self::deposit_event(RawEvent::AccountClosed(closer, bank_id, bank.org()));
+            Ok(())
+        }
+        /// Emergency lever for banks whose controller key is lost, closing the bank
+        /// as if forced by its controller. Dust owed to the closer is burned.
+        #[weight = T::WeightInfo::close_org_bank_account(*members, *proposals)]
+        fn sudo_force_close_bank(
+            origin,
+            bank_id: T::BankId,
+            members: u32,
+            proposals: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let bank = <BankStores<T>>::get(bank_id).ok_or(Error::<T>::CannotCloseBankThatDNE)?;
+            Self::ensure_proposals_witness(bank_id, proposals)?;
+            Self::dissolve_bank(bank_id, bank.org(), None, members)?;
+            Self::deposit_event(RawEvent::BankForceClosed(bank_id, bank.org()));
+            Ok(())
+        }
+        /// Consolidates two banks of one org, moving the source bank's whole balance
+        /// into the target and removing the source. Its proposals must have resolved.
+        /// `proposals` bounds the source bank's proposals checked and cleared
+        #[weight = T::WeightInfo::close_org_bank_account(0, *proposals)]
+        fn merge_banks(
+            origin,
+            source: T::BankId,
+            target: T::BankId,
+            proposals: u32,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            Self::ensure_proposals_witness(source, proposals)?;
+            let merged = Self::merge_bank_into(&caller, source, target)?;
+            Self::deposit_event(RawEvent::BanksMerged(source, target, merged));
+            Ok(())
+        }
+        #[weight = T::WeightInfo::poke_concluded(*max)]
+        fn poke_concluded(
+            origin,
+            max: u32,
+        ) -> DispatchResult {
+            let keeper = ensure_signed(origin)?;
+            let (advanced, more_remain) = Self::poke_concluded_proposals(&keeper, max);
+            Self::deposit_event(RawEvent::ConcludedProposalsPoked(keeper, advanced, more_remain));
+            Ok(())
+        }
+        fn on_initialize(n: T::BlockNumber) -> Weight {
+            let mut weight = Self::clear_expired_rejections(n);
+            // banks are queued on their own cadence, falling back to the global one
+            weight = weight.saturating_add(Self::queue_active_spends(n));
+            weight = weight.saturating_add(Self::queue_active_members(n));
+            // a backlog beyond the cap is drained over the following blocks
+            weight = weight.saturating_add(Self::drain_poll_queues(T::MaxProposalsPolledPerBlock::get()));
+            // after polling so installments of spends approved this block are paid
+            weight.saturating_add(Self::pay_recurring_spends(n))
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Performs computation so don't call unnecessarily
+    pub fn bank_account_id(id: T::BankId) -> T::AccountId {
+        T::BigBank::get().into_sub_account(id)
+    }
+    pub fn bank_balance(bank: T::BankId) -> BalanceOf<T> {
+        <T as Trait>::Currency::total_balance(&Self::bank_account_id(bank))
+    }
+    pub fn bank_free_balance(bank: T::BankId) -> BalanceOf<T> {
+        <T as Trait>::Currency::free_balance(&Self::bank_account_id(bank))
+    }
+    pub fn bank_reserved_balance(bank: T::BankId) -> BalanceOf<T> {
+        <T as Trait>::Currency::reserved_balance(&Self::bank_account_id(bank))
+    }
+    /// Free balance above the minimum deposit the bank must keep
+    pub fn bank_available_to_spend(bank: T::BankId) -> BalanceOf<T> {
+        Self::bank_free_balance(bank).saturating_sub(T::MinDeposit::get())
+    }
+    pub fn is_bank(id: T::BankId) -> bool {
+        <BankStores<T>>::get(id).is_some()
+    }
+    pub fn is_spend(bank: T::BankId, spend: T::SpendId) -> bool {
+        <SpendProps<T>>::get(bank, spend).is_some()
+    }
+    pub fn is_proposal(bank: T::BankId, proposal: T::MemId) -> bool {
+        <MemberProps<T>>::get(bank, proposal).is_some()
+            || <KickProps<T>>::get(bank, proposal).is_some()
+    }
+    fn generate_bank_uid() -> T::BankId {
+        let mut bank_nonce_id = <BankIdNonce<T>>::get() + 1u32.into();
+        while Self::is_bank(bank_nonce_id) {
+            bank_nonce_id += 1u32.into();
+        }
+        <BankIdNonce<T>>::put(bank_nonce_id);
+        bank_nonce_id
+    }
+    fn generate_spend_uid(seed: T::BankId) -> T::SpendId {
+        let mut id_nonce = <SpendNonceMap<T>>::get(seed) + 1u32.into();
+        while Self::is_spend(seed, id_nonce) {
+            id_nonce += 1u32.into();
+        }
+        <SpendNonceMap<T>>::insert(seed, id_nonce);
+        id_nonce
+    }
+    fn generate_proposal_uid(seed: T::BankId) -> T::MemId {
+        let mut id_nonce = <ProposalNonceMap<T>>::get(seed) + 1u32.into();
+        while Self::is_proposal(seed, id_nonce) {
+            id_nonce += 1u32.into();
+        }
+        <ProposalNonceMap<T>>::insert(seed, id_nonce);
+        id_nonce
+    }
+    fn generate_request_uid(seed: T::BankId) -> T::MemId {
+        let mut id_nonce = <RequestNonceMap<T>>::get(seed) + 1u32.into();
+        while <MembershipRequests<T>>::get(seed, id_nonce).is_some() {
+            id_nonce += 1u32.into();
+        }
+        <RequestNonceMap<T>>::insert(seed, id_nonce);
+        id_nonce
+    }
+    /// Removes the bank and everything stored under its id, returning
+    /// bonds still reserved for its unresolved proposals
+    fn unregister_bank(bank_id: T::BankId) {
+        if let Some(bank) = <BankStores<T>>::take(bank_id) {
+            <OrgBankCount<T>>::mutate(bank.org(), |count| {
+                *count = count.saturating_sub(1)
+            });
+            <TotalBankCount>::mutate(|count| *count = count.saturating_sub(1));
+        }
+        let bonded_spends = <SpendBonds<T>>::iter_prefix(bank_id)
+            .map(|(spend_id, _)| spend_id)
+            .collect::<Vec<_>>();
+        bonded_spends
+            .into_iter()
+            .for_each(|spend_id| Self::return_spend_bond(bank_id, spend_id));
+        // live votes are closed so they do not outlive the bank
+        <SpendProps<T>>::iter_prefix_values(bank_id).for_each(|prop| {
+            if let SpendState::Voting(vote_id) = prop.state() {
+                <VoteStarted<T>>::remove(vote_id);
+                let _ = T::VoteMachine::close_vote(vote_id);
+            }
+        });
+        <MemberProps<T>>::iter_prefix_values(bank_id).for_each(|prop| {
+            match prop.state() {
+                ProposalState::Voting(vote_id) => {
+                    <VoteStarted<T>>::remove(vote_id);
+                    let _ = T::VoteMachine::close_vote(vote_id);
+                    Self::return_sponsor_bond(&prop);
+                    Self::refund_tribute(&prop);
+                }
+                ProposalState::WaitingForApproval
+                | ProposalState::FailedQuorum(_) => {
+                    Self::return_sponsor_bond(&prop);
+                    Self::refund_tribute(&prop);
+                }
+                _ => (),
+            }
+        });
+        <KickProps<T>>::iter_prefix_values(bank_id).for_each(|prop| {
+            if let ProposalState::Voting(vote_id) = prop.state() {
+                <VoteStarted<T>>::remove(vote_id);
+                let _ = T::VoteMachine::close_vote(vote_id);
+            }
+        });
+        <MembershipRequests<T>>::iter_prefix_values(bank_id).for_each(
+            |(applicant, tribute, _)| {
+                <T as Trait>::Currency::unreserve(&applicant, tribute);
+            },
+        );
+        <SpendNonceMap<T>>::remove(bank_id);
+        <ProposalNonceMap<T>>::remove(bank_id);
+        <RequestNonceMap<T>>::remove(bank_id);
+        <MembershipRequests<T>>::remove_prefix(bank_id);
+        <SpendProps<T>>::remove_prefix(bank_id);
+        <MemberProps<T>>::remove_prefix(bank_id);
+        <KickProps<T>>::remove_prefix(bank_id);
+        <ActiveSpendProps<T>>::remove(bank_id);
+        <ActiveMemberProps<T>>::remove(bank_id);
+        <ActiveKickProps<T>>::remove(bank_id);
+        <SpendExpiry<T>>::remove_prefix(bank_id);
+        <MemberExpiry<T>>::remove_prefix(bank_id);
+        <KickExpiry<T>>::remove_prefix(bank_id);
+        <SpendConditions<T>>::remove_prefix(bank_id);
+        <SplitPayouts<T>>::remove_prefix(bank_id);
+        <DividendSpends<T>>::remove_prefix(bank_id);
+        <InterBankSpends<T>>::remove_prefix(bank_id);
+        <LockedShares<T>>::remove_prefix(bank_id);
+        <RecurringSpends<T>>::remove_prefix(bank_id);
+        <SpendGraceEnds<T>>::remove_prefix(bank_id);
+        <SpendCapPerWindow<T>>::remove(bank_id);
+        <SpendWindowUsage<T>>::remove(bank_id);
+        <CapDeferredSpends<T>>::remove_prefix(bank_id);
+        <SpendFeesCharged<T>>::remove_prefix(bank_id);
+        <SpendReservations<T>>::remove_prefix(bank_id);
+        <SpendMetadata<T>>::remove_prefix(bank_id);
+        <MemberMetadata<T>>::remove_prefix(bank_id);
+        <MemberThresholdOverrides<T>>::remove_prefix(bank_id);
+        <AutomatedSpendCount<T>>::remove(bank_id);
+        <RecentRejections<T>>::remove_prefix(bank_id);
+        <MemberStats<T>>::remove_prefix(bank_id);
+        <Loot<T>>::remove_prefix(bank_id);
+        <TotalLoot<T>>::remove(bank_id);
+        <RequireDestAllowlist<T>>::remove(bank_id);
+        <FrozenBanks<T>>::remove(bank_id);
+        <DestAllowlist<T>>::remove_prefix(bank_id);
+        <Observers<T>>::remove_prefix(bank_id);
+        <Delegates<T>>::remove_prefix(bank_id);
+        <Delegators<T>>::remove_prefix(bank_id);
+        <OpenProposalCount<T>>::remove_prefix(bank_id);
+        <SpendTallies<T>>::remove_prefix(bank_id);
+        <MemberTallies<T>>::remove_prefix(bank_id);
+        <SpendOpenedBy<T>>::remove_prefix(bank_id);
+        <MemberOpenedBy<T>>::remove_prefix(bank_id);
+        <BankQuorum<T>>::remove(bank_id);
+        <SpendThreshold<T>>::remove(bank_id);
+        <MemberThreshold<T>>::remove(bank_id);
+        <MaxVoteAge<T>>::remove(bank_id);
+        <ProposalFee<T>>::remove(bank_id);
+        <SponsorBond<T>>::remove(bank_id);
+        <SlashedBondDest<T>>::remove(bank_id);
+        <RagequitFee<T>>::remove(bank_id);
+        <TributeMatch<T>>::remove(bank_id);
+        <MinTributePerShare<T>>::remove(bank_id);
+        <ConvictionEnabled<T>>::remove(bank_id);
+        <CloseDustDest<T>>::remove(bank_id);
+        <MaxTotalShares<T>>::remove(bank_id);
+        <BankMaxSharesPerMember<T>>::remove(bank_id);
+        <ReproposalCooloff<T>>::remove(bank_id);
+        <BankSpendPollFrequency<T>>::remove(bank_id);
+        <BankMemberPollFrequency<T>>::remove(bank_id);
+    }
+    pub fn is_allowlisted_dest(bank: T::BankId, dest: &T::AccountId) -> bool {
+        !<RequireDestAllowlist<T>>::get(bank)
+            || <DestAllowlist<T>>::get(bank, dest).is_some()
+            || *dest == Self::bank_account_id(bank)
+    }
+    /// Transfers the bank's proposal fee from the proposer into the bank
+    fn charge_proposal_fee(
+        proposer: &T::AccountId,
+        bank_id: T::BankId,
+    ) -> DispatchResult {
+        let fee = <ProposalFee<T>>::get(bank_id);
+        if !fee.is_zero() {
+            <T as Trait>::Currency::transfer(
+                proposer,
+                &Self::bank_account_id(bank_id),
+                fee,
+                ExistenceRequirement::KeepAlive,
+            )
+            .map_err(|_| Error::<T>::CannotAffordProposalFee)?;
+        }
+        Ok(())
+    }
+    /// Clears the payout tables and condition of a spend that will never pay out,
+    /// releasing any funds reserved for it
+    fn clear_spend_side_tables(bank_id: T::BankId, spend_id: T::SpendId) {
+        <SplitPayouts<T>>::remove(bank_id, spend_id);
+        <DividendSpends<T>>::remove(bank_id, spend_id);
+        <InterBankSpends<T>>::remove(bank_id, spend_id);
+        <RecurringSpends<T>>::remove(bank_id, spend_id);
+        Self::clear_spend_condition(bank_id, spend_id);
+        Self::release_spend_funds(bank_id, spend_id);
+    }
+    /// Releases the bank funds reserved while the spend was in a live vote
+    fn release_spend_funds(bank_id: T::BankId, spend_id: T::SpendId) {
+        if let Some(amount) = <SpendReservations<T>>::take(bank_id, spend_id) {
+            <T as Trait>::Currency::unreserve(
+                &Self::bank_account_id(bank_id),
+                amount,
+            );
+        }
+    }
+    fn return_spend_bond(bank_id: T::BankId, spend_id: T::SpendId) {
+        if let Some((proposer, bond)) = <SpendBonds<T>>::take(bank_id, spend_id)
+        {
+            <T as Trait>::Currency::unreserve(&proposer, bond);
+            Self::deposit_event(RawEvent::SpendBondReturned(
+                bank_id, spend_id, proposer, bond,
+            ));
+        }
+    }
+    fn slash_spend_bond(bank_id: T::BankId, spend_id: T::SpendId) {
+        if let Some((proposer, bond)) = <SpendBonds<T>>::take(bank_id, spend_id)
+        {
+            let dest = <SlashedBondDest<T>>::get(bank_id);
+            match dest {
+                SlashedBondDestination::Bank => {
+                    let _ = <T as Trait>::Currency::repatriate_reserved(
+                        &proposer,
+                        &Self::bank_account_id(bank_id),
+                        bond,
+                        BalanceStatus::Free,
+                    );
+                }
+                SlashedBondDestination::Burn => {
+                    // dropping the negative imbalance reduces total issuance
+                    let _ =
+                        <T as Trait>::Currency::slash_reserved(&proposer, bond);
+                }
+                SlashedBondDestination::Treasury => {
+                    let (imbalance, _) =
+                        <T as Trait>::Currency::slash_reserved(&proposer, bond);
+                    T::SlashedBondTreasury::on_unbalanced(imbalance);
+                }
+            }
+            Self::deposit_event(RawEvent::SpendBondSlashed(
+                bank_id, spend_id, proposer, bond, dest,
+            ));
+        }
+    }
+    fn return_sponsor_bond(prop: &MemberProp<T>) {
+        if let Some((sponsor, bond)) = prop.sponsor_bond() {
+            <T as Trait>::Currency::unreserve(&sponsor, bond);
+            Self::deposit_event(RawEvent::SponsorBondReturned(
+                prop.bank_id(),
+                prop.prop_id(),
+                sponsor,
+                bond,
+            ));
+        }
+    }
+    fn slash_sponsor_bond(prop: &MemberProp<T>) {
+        if let Some((sponsor, bond)) = prop.sponsor_bond() {
+            let _ = <T as Trait>::Currency::repatriate_reserved(
+                &sponsor,
+                &Self::bank_account_id(prop.bank_id()),
+                bond,
+                BalanceStatus::Free,
+            );
+            Self::deposit_event(RawEvent::SponsorBondSlashed(
+                prop.bank_id(),
+                prop.prop_id(),
+                sponsor,
+                bond,
+            ));
+        }
+    }
+    /// Moves an applicant's request into the votable proposal set, the escrowed
+    /// tribute stays reserved and is now tracked by the proposal
+    fn sponsor_request(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        request_id: T::MemId,
+    ) -> Result<T::MemId, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::BankMustExistToProposeFrom)?;
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), caller),
+            Error::<T>::MustBeMemberToSponsorProposal
+        );
+        let (applicant, tribute, shares_requested) =
+            <MembershipRequests<T>>::get(bank_id, request_id)
+                .ok_or(Error::<T>::CannotSponsorRequestThatDNE)?;
+        ensure!(
+            <T as Trait>::Currency::reserved_balance(&applicant) >= tribute,
+            Error::<T>::ApplicantLacksEscrowedTribute
+        );
+        Self::ensure_reproposal_allowed(
+            bank_id,
+            Self::member_key(&applicant, shares_requested),
+        )?;
+        let id = Self::open_member_proposal(
+            caller,
+            bank_id,
+            tribute,
+            shares_requested,
+            Zero::zero(),
+            applicant,
+        )?;
+        <MembershipRequests<T>>::remove(bank_id, request_id);
+        Ok(id)
+    }
+    /// Reserves the sponsor bond and charges the proposal fee before storing the
+    /// proposal, expects the tribute to already be escrowed
+    fn open_member_proposal(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        tribute: BalanceOf<T>,
+        shares_requested: T::Shares,
+        loot_requested: T::Shares,
+        applicant: T::AccountId,
+    ) -> Result<T::MemId, DispatchError> {
+        if let Some(ratio) = <MinTributePerShare<T>>::get(bank_id) {
+            let min_tribute = ratio
+                .saturated_into::<u128>()
+                .saturating_mul(shares_requested.saturated_into::<u128>());
+            ensure!(
+                tribute.saturated_into::<u128>() >= min_tribute,
+                Error::<T>::TributeBelowMinimumPerShare
+            );
+        }
+        Self::ensure_below_open_proposal_cap(bank_id, caller)?;
+        let bond = <SponsorBond<T>>::get(bank_id);
+        if !bond.is_zero()
+            && <T as Trait>::Currency::reserve(caller, bond).is_err()
+        {
+            return Err(
+                Error::<T>::InsufficientBalanceToReserveSponsorBond.into()
+            )
+        }
+        if let Err(e) = Self::charge_proposal_fee(caller, bank_id) {
+            <T as Trait>::Currency::unreserve(caller, bond);
+            return Err(e)
+        }
+        let id = Self::generate_proposal_uid(bank_id);
+        let member_proposal = MemberProp::<T>::new(
+            bank_id,
+            id,
+            tribute,
+            shares_requested,
+            loot_requested,
+            applicant,
+        );
+        let member_proposal = if bond.is_zero() {
+            member_proposal
+        } else {
+            member_proposal.set_sponsor_bond(caller.clone(), bond)
+        };
+        <MemberProps<T>>::insert(bank_id, id, member_proposal);
+        <ActiveMemberProps<T>>::mutate(bank_id, |ids| ids.push(id));
+        Self::track_open_proposal(bank_id, id, caller);
+        <MemberExpiry<T>>::insert(bank_id, id, Self::proposal_expiry());
+        Self::log_activity(bank_id, BankActivity::MemberProposed(id));
+        Ok(id)
+    }
+    /// Releases the tribute escrowed for the proposal back to the applicant
+    fn refund_tribute(prop: &MemberProp<T>) {
+        if Self::release_tribute(prop) {
+            Self::deposit_event(RawEvent::TributeRefunded(
+                prop.bank_id(),
+                prop.prop_id(),
+                prop.applicant(),
+                prop.tribute(),
+            ));
+        }
+    }
+    /// Unreserves the proposal's tribute, returning false if there was none
+    fn release_tribute(prop: &MemberProp<T>) -> bool {
+        if prop.tribute().is_zero() {
+            return false
+        }
+        <T as Trait>::Currency::unreserve(&prop.applicant(), prop.tribute());
+        true
+    }
+    /// Expiry block for a proposal made in the current block
+    fn proposal_expiry() -> T::BlockNumber {
+        <frame_system::Module<T>>::block_number() + T::ProposalTtl::get()
+    }
+    /// True if the expiry block has passed
+    fn proposal_expired(expiry: Option<T::BlockNumber>) -> bool {
+        expiry
+            .map(|e| <frame_system::Module<T>>::block_number() > e)
+            .unwrap_or(false)
+    }
+    /// Removes a spend proposal that expired without a live vote, returning its bond
+    fn expire_spend_proposal(prop: &SpendProp<T>) {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        <SpendProps<T>>::remove(bank_id, spend_id);
+        <SpendExpiry<T>>::remove(bank_id, spend_id);
+        <SpendMetadata<T>>::remove(bank_id, spend_id);
+        Self::clear_spend_side_tables(bank_id, spend_id);
+        Self::return_spend_bond(bank_id, spend_id);
+        Self::deposit_event(RawEvent::SpendProposalExpired(bank_id, spend_id));
+    }
+    /// Removes a membership proposal that expired without a live vote, returning
+    /// its sponsor bond and tribute
+    fn expire_member_proposal(prop: &MemberProp<T>) {
+        <MemberProps<T>>::remove(prop.bank_id(), prop.prop_id());
+        <MemberExpiry<T>>::remove(prop.bank_id(), prop.prop_id());
+        <MemberMetadata<T>>::remove(prop.bank_id(), prop.prop_id());
+        <MemberThresholdOverrides<T>>::remove(prop.bank_id(), prop.prop_id());
+        Self::return_sponsor_bond(prop);
+        if Self::release_tribute(prop) {
+            Self::deposit_event(RawEvent::ExpiredMembershipTributeRefunded(
+                prop.bank_id(),
+                prop.prop_id(),
+                prop.applicant(),
+                prop.tribute(),
+            ));
+        }
+        Self::deposit_event(RawEvent::MemberProposalExpired(
+            prop.bank_id(),
+            prop.prop_id(),
+        ));
+    }
+    /// Removes a kick proposal that expired without a live vote
+    fn expire_kick_proposal(prop: &KickProp<T>) {
+        <KickProps<T>>::remove(prop.bank_id(), prop.prop_id());
+        <KickExpiry<T>>::remove(prop.bank_id(), prop.prop_id());
+        Self::deposit_event(RawEvent::KickProposalExpired(
+            prop.bank_id(),
+            prop.prop_id(),
+        ));
+    }
+    fn spend_key(amount: BalanceOf<T>, dest: &T::AccountId) -> T::Hash {
+        T::Hashing::hash_of(&(b"spend", amount, dest))
+    }
+    fn member_key(applicant: &T::AccountId, shares: T::Shares) -> T::Hash {
+        T::Hashing::hash_of(&(b"member", applicant, shares))
+    }
+    /// Blocks identical proposals until the bank's cool-off passes
+    fn record_rejection(bank_id: T::BankId, key: T::Hash) {
+        let cooloff = <ReproposalCooloff<T>>::get(bank_id);
+        if !cooloff.is_zero() {
+            let allowed_at =
+                <frame_system::Module<T>>::block_number() + cooloff;
+            <RecentRejections<T>>::insert(bank_id, key, allowed_at);
+            <RejectionExpiries<T>>::mutate(allowed_at, |expiring| {
+                expiring.push((bank_id, key))
+            });
+        }
+    }
+    fn ensure_reproposal_allowed(
+        bank_id: T::BankId,
+        key: T::Hash,
+    ) -> DispatchResult {
+        if let Some(allowed_at) = <RecentRejections<T>>::get(bank_id, key) {
+            ensure!(
+                <frame_system::Module<T>>::block_number() >= allowed_at,
+                Error::<T>::ReproposalTooSoon
+            );
+            <RecentRejections<T>>::remove(bank_id, key);
+        }
+        Ok(())
+    }
+    /// Clears the rejections expiring at block `n` unless since re-recorded for a
+    /// later block, returning the weight consumed
+    fn clear_expired_rejections(n: T::BlockNumber) -> Weight {
+        let expiring = <RejectionExpiries<T>>::take(n);
+        let (mut reads, mut writes) = (1u64, 1u64);
+        for (bank_id, key) in expiring {
+            reads += 1;
+            if <RecentRejections<T>>::get(bank_id, key) == Some(n) {
+                <RecentRejections<T>>::remove(bank_id, key);
+                writes += 1;
+            }
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Burns loot and pays out its proportion of the bank, leaving voting shares intact
+    fn _claim_loot(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        amount: T::Shares,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotClaimLootIfBankDNE)?;
+        let loot = <Loot<T>>::get(bank_id, caller);
+        ensure!(
+            !amount.is_zero() && amount <= loot,
+            Error::<T>::InsufficientLootToClaim
+        );
+        // economic base is voting shares plus loot
+        let total_loot = <TotalLoot<T>>::get(bank_id);
+        let economic_base =
+            <org::Module<T>>::outstanding_shares(bank.org()) + total_loot;
+        let payout = Self::economic_portion(
+            Self::bank_balance(bank_id),
+            amount,
+            economic_base,
+        );
+        <T as Trait>::Currency::transfer(
+            &Self::bank_account_id(bank_id),
+            caller,
+            payout,
+            ExistenceRequirement::KeepAlive,
+        )
+        .map_err(|_| Error::<T>::BankCannotCoverLootClaim)?;
+        if loot == amount {
+            <Loot<T>>::remove(bank_id, caller);
+        } else {
+            <Loot<T>>::insert(bank_id, caller, loot - amount);
+        }
+        <TotalLoot<T>>::insert(bank_id, total_loot - amount);
+        Ok(payout)
+    }
+    /// Pays each holder their share of `amount` by ownership of `total`, escrowing
+    /// the shares that cannot be transferred and returning their total
+    fn distribute_close_proceeds(
+        bank_id: T::BankId,
+        holders: Vec<(T::AccountId, T::Shares)>,
+        total: T::Shares,
+        amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let bank_account_id = Self::bank_account_id(bank_id);
+        let mut escrowed = BalanceOf::<T>::zero();
+        holders.into_iter().for_each(|(acc, shares)| {
+            // rounds down into the dust swept after
+            let due = Self::economic_portion(amount, shares, total);
+            if !due.is_zero()
+                && <T as Trait>::Currency::transfer(
+                    &bank_account_id,
+                    &acc,
+                    due,
+                    ExistenceRequirement::KeepAlive,
+                )
+                .is_err()
+            {
+                <UnclaimedDissolution<T>>::mutate(bank_id, &acc, |b| *b += due);
+                escrowed += due;
+                Self::deposit_event(RawEvent::DissolutionProceedsEscrowed(
+                    bank_id, acc, due,
+                ));
+            }
+        });
+        escrowed
+    }
+    /// Every holder of the bank's shares or loot paired with their combined
+    /// holding, and the total those holdings are a portion of
+    fn economic_holders(
+        bank_id: T::BankId,
+        org: T::OrgId,
+    ) -> Result<(Vec<(T::AccountId, T::Shares)>, T::Shares), DispatchError>
+    {
+        let group = <org::Module<T>>::get_group(org)
+            .ok_or(Error::<T>::CannotDistributeToOrgThatDNE)?;
+        // loot counts toward each holder's proportion, including holders without votes
+        let total = <org::Module<T>>::outstanding_shares(org)
+            + <TotalLoot<T>>::get(bank_id);
+        let mut holders = group
+            .0
+            .into_iter()
+            .map(|acc| {
+                let shares = <org::Module<T>>::members(org, &acc)
+                    .map(|profile| profile.total())
+                    .unwrap_or_else(Zero::zero);
+                let loot = <Loot<T>>::get(bank_id, &acc);
+                (acc, shares + loot)
+            })
+            .collect::<Vec<_>>();
+        holders.extend(
+            <Loot<T>>::iter_prefix(bank_id).filter(|(acc, _)| {
+                <org::Module<T>>::members(org, acc).is_none()
+            }),
+        );
+        Ok((holders, total))
+    }
+    /// Exactly `amount * shares / total`, rounded down
+    fn economic_portion(
+        amount: BalanceOf<T>,
+        shares: T::Shares,
+        total: T::Shares,
+    ) -> BalanceOf<T> {
+        multiply_by_rational(
+            amount.saturated_into::<u128>(),
+            shares.saturated_into::<u128>(),
+            total.saturated_into::<u128>(),
+        )
+        .map(|portion| portion.saturated_into())
+        .unwrap_or_else(|_| Zero::zero())
+    }
+    /// Distributes the bank's funds, including those reserved for live spend
+    /// votes, sweeps the dust and removes the bank with all its proposals,
+    /// unless it has more holders than `members`
+    fn dissolve_bank(
+        bank_id: T::BankId,
+        org: T::OrgId,
+        closer: Option<&T::AccountId>,
+        members: u32,
+    ) -> DispatchResult {
+        let (holders, total) = Self::economic_holders(bank_id, org)?;
+        ensure!(
+            holders.len() <= members as usize,
+            Error::<T>::BankSizeWitnessTooLow
+        );
+        let bank_account_id = Self::bank_account_id(bank_id);
+        <SpendReservations<T>>::iter_prefix(bank_id).for_each(|(_, amount)| {
+            <T as Trait>::Currency::unreserve(&bank_account_id, amount);
+        });
+        let remaining_funds =
+            <T as Trait>::Currency::total_balance(&bank_account_id);
+        // distributes remaining funds in proportion to ownership, keeping the
+        // existential deposit and rounding remainder in the bank for the final sweep
+        let distributable = remaining_funds
+            .saturating_sub(<T as Trait>::Currency::minimum_balance());
+        let escrowed = Self::distribute_close_proceeds(
+            bank_id,
+            holders,
+            total,
+            distributable,
+        );
+        Self::sweep_close_dust(bank_id, closer, escrowed)?;
+        Self::unregister_bank(bank_id);
+        Self::log_activity(bank_id, BankActivity::Closed);
+        Ok(())
+    }
+    /// Transfers the source bank's balance to the target and unregisters the
+    /// source, returning the amount moved
+    fn merge_bank_into(
+        caller: &T::AccountId,
+        source: T::BankId,
+        target: T::BankId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        ensure!(source != target, Error::<T>::CannotMergeBankIntoItself);
+        let source_bank = Self::ensure_controller(caller, source)?;
+        let target_bank = Self::ensure_controller(caller, target)?;
+        ensure!(
+            source_bank.org() == target_bank.org(),
+            Error::<T>::CannotMergeBanksOfDifferentOrgs
+        );
+        ensure!(
+            !<FrozenBanks<T>>::get(source) && !<FrozenBanks<T>>::get(target),
+            Error::<T>::BankFrozen
+        );
+        ensure!(
+            !Self::has_live_proposals(source),
+            Error::<T>::CannotMergeBankWithLiveProposals
+        );
+        let source_account = Self::bank_account_id(source);
+        <SpendReservations<T>>::iter_prefix(source).for_each(|(_, amount)| {
+            <T as Trait>::Currency::unreserve(&source_account, amount);
+        });
+        let merged = <T as Trait>::Currency::free_balance(&source_account);
+        <T as Trait>::Currency::transfer(
+            &source_account,
+            &Self::bank_account_id(target),
+            merged,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        Self::unregister_bank(source);
+        Self::log_activity(source, BankActivity::Closed);
+        Ok(merged)
+    }
+    /// Sweeps what is left in the bank after the close distribution, keeping
+    /// `escrowed` (and the existential deposit with it) for later claims.
+    /// Without a closer the dust is burned.
+    fn sweep_close_dust(
+        bank_id: T::BankId,
+        closer: Option<&T::AccountId>,
+        escrowed: BalanceOf<T>,
+    ) -> DispatchResult {
+        let bank_account_id = Self::bank_account_id(bank_id);
+        let retained = if escrowed.is_zero() {
+            escrowed
+        } else {
+            escrowed.max(<T as Trait>::Currency::minimum_balance())
+        };
+        let dust = <T as Trait>::Currency::free_balance(&bank_account_id)
+            .saturating_sub(retained);
+        let dest = match (<CloseDustDest<T>>::get(bank_id), closer) {
+            (CloseDustDestination::Closer, Some(_)) => {
+                CloseDustDestination::Closer
+            }
+            _ => CloseDustDestination::Burn,
+        };
+        match (dest, closer) {
+            (CloseDustDestination::Closer, Some(closer)) => {
+                <T as Trait>::Currency::transfer(
+                    &bank_account_id,
+                    closer,
+                    dust,
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            _ => {
+                // dropping the negative imbalance reduces total issuance
+                let _ = <T as Trait>::Currency::slash(&bank_account_id, dust);
+            }
+        }
+        Self::deposit_event(RawEvent::CloseDustSwept(bank_id, dust, dest));
+        Ok(())
+    }
+    fn condition_holds(condition: SpendCond<T>) -> bool {
+        match condition {
+            SpendCondition::AfterBlock(n) => {
+                <frame_system::Module<T>>::block_number() >= n
+            }
+            SpendCondition::IfExternalFlag(key) => {
+                T::ExternalFlags::is_set(&key)
+            }
+        }
+    }
+    /// Removes the spend's condition, returning true if it had one
+    fn clear_spend_condition(bank_id: T::BankId, spend_id: T::SpendId) -> bool {
+        if <SpendConditions<T>>::take(bank_id, spend_id).is_some() {
+            <AutomatedSpendCount<T>>::mutate(bank_id, |count| {
+                *count = count.saturating_sub(1)
+            });
+            true
+        } else {
+            false
+        }
+    }
+    /// Executes an approved spend unless its condition does not hold yet
+    fn execute_spend(prop: SpendProp<T>) -> SpendProp<T> {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        if let Some(condition) = <SpendConditions<T>>::get(bank_id, spend_id) {
+            if !Self::condition_holds(condition) {
+                if prop.state() != SpendState::ApprovedButNotExecuted {
+                    Self::deposit_event(RawEvent::ConditionalSpendPending(
+                        bank_id, spend_id,
+                    ));
+                }
+                return prop.set_state(SpendState::ApprovedButNotExecuted)
+            }
+        }
+        // recurring spends are capped per installment instead
+        if !<RecurringSpends<T>>::contains_key(bank_id, spend_id) {
+            let due = <SplitPayouts<T>>::get(bank_id, spend_id)
+                .map(|payouts| {
+                    payouts
+                        .iter()
+                        .fold(BalanceOf::<T>::zero(), |acc, (_, amt)| {
+                            acc.saturating_add(*amt)
+                        })
+                })
+                .unwrap_or_else(|| prop.amount());
+            if !Self::within_spend_cap(bank_id, due) {
+                // funds stay reserved until a later window has room
+                <CapDeferredSpends<T>>::insert(bank_id, spend_id, true);
+                Self::deposit_event(RawEvent::SpendCapReached(bank_id));
+                return prop.set_state(SpendState::ApprovedButNotExecuted)
+            }
+            <CapDeferredSpends<T>>::remove(bank_id, spend_id);
+        }
+        Self::release_spend_funds(bank_id, spend_id);
+        if <DividendSpends<T>>::get(bank_id, spend_id) {
+            return Self::execute_dividend(prop)
+        }
+        if let Some(to_bank) = <InterBankSpends<T>>::get(bank_id, spend_id) {
+            // funds stay put if the receiving bank closed since the proposal
+            if !Self::is_bank(to_bank) {
+                return prop.set_state(SpendState::ApprovedButNotExecuted)
+            }
+        }
+        if let Some(payouts) = <SplitPayouts<T>>::get(bank_id, spend_id) {
+            return Self::execute_split_spend(prop, payouts)
+        }
+        if let Some(schedule) = <RecurringSpends<T>>::get(bank_id, spend_id) {
+            // the first installment is paid from the next block
+            if schedule.next_payout.is_none() {
+                Self::schedule_recurring_payout(
+                    bank_id,
+                    spend_id,
+                    schedule,
+                    <frame_system::Module<T>>::block_number() + 1u32.into(),
+                );
+            }
+            return prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+        // locks on the bank account fail here with a precise error
+        if let Err(e) = Self::ensure_bank_can_withdraw(bank_id, prop.amount()) {
+            Self::deposit_event(RawEvent::SpendWithdrawalBlocked(
+                bank_id, spend_id, e,
+            ));
+            return prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+        if let Ok(()) = Self::pay_spend_with_fee(
+            bank_id,
+            spend_id,
+            &prop.dest(),
+            prop.amount(),
+        ) {
+            Self::record_capped_spend(bank_id, prop.amount());
+            if let Some(to_bank) = <InterBankSpends<T>>::take(bank_id, spend_id)
+            {
+                Self::deposit_event(RawEvent::InterBankSpendExecuted(
+                    bank_id,
+                    spend_id,
+                    to_bank,
+                    prop.amount(),
+                ));
+            }
+            if Self::clear_spend_condition(bank_id, spend_id) {
+                Self::deposit_event(RawEvent::ConditionalSpendExecuted(
+                    bank_id,
+                    spend_id,
+                    prop.amount(),
+                    prop.dest(),
+                ));
+            }
+            prop.set_state(SpendState::ApprovedAndExecuted)
+        } else {
+            prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+    }
+    /// Pays `amount` less the spend fee to `dest` and the fee to the fee destination,
+    /// moving nothing unless both transfers succeed, for every payout a spend makes
+    fn pay_spend_with_fee(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        dest: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let bank_account = Self::bank_account_id(bank_id);
+        let fee = T::SpendFee::get().mul_floor(amount);
+        let fee_dest = T::FeeDestination::get();
+        with_transaction(|| {
+            if !fee.is_zero() {
+                if let Err(e) = <T as Trait>::Currency::transfer(
+                    &bank_account,
+                    &fee_dest,
+                    fee,
+                    ExistenceRequirement::KeepAlive,
+                ) {
+                    return TransactionOutcome::Rollback(Err(e))
+                }
+            }
+            if let Err(e) = <T as Trait>::Currency::transfer(
+                &bank_account,
+                dest,
+                amount.saturating_sub(fee),
+                ExistenceRequirement::KeepAlive,
+            ) {
+                return TransactionOutcome::Rollback(Err(e))
+            }
+            TransactionOutcome::Commit(Ok(()))
+        })?;
+        if !fee.is_zero() {
+            <SpendFeesCharged<T>>::mutate(bank_id, spend_id, |charged| {
+                *charged =
+                    Some(charged.unwrap_or_else(Zero::zero).saturating_add(fee))
+            });
+            Self::deposit_event(RawEvent::SpendFeeCharged(
+                bank_id, spend_id, fee, fee_dest,
+            ));
+        }
+        Ok(())
+    }
+    /// Checks the bank account's free balance and locks allow transferring `amount`
+    fn ensure_bank_can_withdraw(
+        bank_id: T::BankId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let bank_account = Self::bank_account_id(bank_id);
+        let new_balance = <T as Trait>::Currency::free_balance(&bank_account)
+            .checked_sub(&amount)
+            .ok_or(Error::<T>::InsufficientBankBalanceToExecuteSpend)?;
+        <T as Trait>::Currency::ensure_can_withdraw(
+            &bank_account,
+            amount,
+            WithdrawReason::Transfer.into(),
+            new_balance,
+        )
+    }
+    /// Pays the dividend to the bank's holders in proportion to their shares and
+    /// loot, waiting for a retry if it would leave less than the minimum deposit
+    /// free or any holder cannot be paid
+    fn execute_dividend(prop: SpendProp<T>) -> SpendProp<T> {
+        let (bank_id, spend_id, amount) =
+            (prop.bank_id(), prop.spend_id(), prop.amount());
+        // funds reserved for other live spend votes cannot be paid out
+        if amount > Self::bank_available_to_spend(bank_id) {
+            return prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+        let holders = <BankStores<T>>::get(bank_id)
+            .ok_or_else(|| Error::<T>::CannotDistributeToOrgThatDNE.into())
+            .and_then(|bank| Self::economic_holders(bank_id, bank.org()));
+        let (holders, total) = if let Ok(holders) = holders {
+            holders
+        } else {
+            return prop.set_state(SpendState::ApprovedButNotExecuted)
+        };
+        // rounding leaves its remainder in the bank
+        let paid = with_transaction(|| {
+            let mut paid = BalanceOf::<T>::zero();
+            for (acc, shares) in holders {
+                let due = Self::economic_portion(amount, shares, total);
+                if due.is_zero() {
+                    continue
+                }
+                if let Err(e) =
+                    Self::pay_spend_with_fee(bank_id, spend_id, &acc, due)
+                {
+                    return TransactionOutcome::Rollback(Err(e))
+                }
+                paid = paid.saturating_add(due);
+            }
+            TransactionOutcome::Commit(Ok(paid))
+        });
+        let paid = if let Ok(paid) = paid {
+            paid
+        } else {
+            return prop.set_state(SpendState::ApprovedButNotExecuted)
+        };
+        Self::record_capped_spend(bank_id, paid);
+        <DividendSpends<T>>::remove(bank_id, spend_id);
+        Self::deposit_event(RawEvent::DividendDistributed(bank_id, paid));
+        prop.set_state(SpendState::ApprovedAndExecuted)
+    }
+    /// Transfers every outstanding leg of a split spend, keeping the failed legs
+    /// so that retrying only pays those
+    fn execute_split_spend(
+        prop: SpendProp<T>,
+        payouts: Vec<(T::AccountId, BalanceOf<T>)>,
+    ) -> SpendProp<T> {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        let legs = payouts.len() as u32;
+        let failed = payouts
+            .into_iter()
+            .filter(|(dest, amount)| {
+                let paid =
+                    Self::pay_spend_with_fee(bank_id, spend_id, dest, *amount)
+                        .is_ok();
+                if paid {
+                    Self::record_capped_spend(bank_id, *amount);
+                }
+                !paid
+            })
+            .collect::<Vec<_>>();
+        Self::deposit_event(RawEvent::SplitSpendExecuted(
+            bank_id,
+            spend_id,
+            legs - failed.len() as u32,
+        ));
+        if failed.is_empty() {
+            <SplitPayouts<T>>::remove(bank_id, spend_id);
+            Self::clear_spend_condition(bank_id, spend_id);
+            prop.set_state(SpendState::ApprovedAndExecuted)
+        } else {
+            <SplitPayouts<T>>::insert(bank_id, spend_id, failed);
+            prop.set_state(SpendState::ApprovedButNotExecuted)
+        }
+    }
+    /// Queues the recurring spend's next installment to be paid at block `at`
+    fn schedule_recurring_payout(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        schedule: RecurringSched<T>,
+        at: T::BlockNumber,
+    ) {
+        <RecurringSpends<T>>::insert(
+            bank_id,
+            spend_id,
+            RecurringSchedule {
+                next_payout: Some(at),
+                ..schedule
+            },
+        );
+        <RecurringPayoutsDue<T>>::mutate(at, |due| {
+            due.push((bank_id, spend_id))
+        });
+    }
+    /// Pays the installments of recurring spends due at block `n`, returning the
+    /// weight consumed
+    fn pay_recurring_spends(n: T::BlockNumber) -> Weight {
+        let due = <RecurringPayoutsDue<T>>::take(n);
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+        for (bank_id, spend_id) in due {
+            weight = weight.saturating_add(Self::pay_recurring_installment(
+                n, bank_id, spend_id,
+            ));
+        }
+        weight
+    }
+    /// Pays one installment, a failed transfer misses that installment without
+    /// ending the schedule while a frozen bank or full spend cap defers it
+    fn pay_recurring_installment(
+        n: T::BlockNumber,
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+    ) -> Weight {
+        let db = T::DbWeight::get();
+        // cancelled schedules simply drop out of the queue
+        let schedule = match <RecurringSpends<T>>::get(bank_id, spend_id) {
+            Some(schedule) if schedule.next_payout == Some(n) => schedule,
+            _ => return db.reads(1),
+        };
+        let prop = if let Some(prop) = <SpendProps<T>>::get(bank_id, spend_id) {
+            prop
+        } else {
+            <RecurringSpends<T>>::remove(bank_id, spend_id);
+            return db.reads_writes(2, 1)
+        };
+        // stays due until the bank is unfrozen and a window has room for it
+        if <FrozenBanks<T>>::get(bank_id)
+            || !Self::within_spend_cap(bank_id, schedule.amount_per_period)
+        {
+            Self::schedule_recurring_payout(
+                bank_id,
+                spend_id,
+                schedule,
+                n + 1u32.into(),
+            );
+            return db.reads_writes(5, 3)
+        }
+        let paid = if Self::pay_spend_with_fee(
+            bank_id,
+            spend_id,
+            &prop.dest(),
+            schedule.amount_per_period,
+        )
+        .is_ok()
+        {
+            Self::record_capped_spend(bank_id, schedule.amount_per_period);
+            schedule.amount_per_period
+        } else {
+            Zero::zero()
+        };
+        Self::deposit_event(RawEvent::RecurringSpendPayout(
+            bank_id,
+            spend_id,
+            schedule.num_periods - schedule.remaining + 1,
+            paid,
+        ));
+        let remaining = schedule.remaining - 1;
+        if remaining == 0 {
+            <RecurringSpends<T>>::remove(bank_id, spend_id);
+            <SpendProps<T>>::insert(
+                bank_id,
+                spend_id,
+                prop.set_state(SpendState::ApprovedAndExecuted),
+            );
+        } else {
+            let next_payout = n + schedule.period;
+            Self::schedule_recurring_payout(
+                bank_id,
+                spend_id,
+                RecurringSchedule {
+                    remaining,
+                    ..schedule
+                },
+                next_payout,
+            );
+        }
+        db.reads_writes(7, 6)
+    }
+    /// True if `amount` fits in what remains of the bank's spend cap, starting
+    /// a new window if the current one has passed
+    fn within_spend_cap(bank_id: T::BankId, amount: BalanceOf<T>) -> bool {
+        if let Some((cap, spent, window_passed)) =
+            Self::spend_cap_usage(bank_id)
+        {
+            if window_passed {
+                <SpendWindowUsage<T>>::insert(
+                    bank_id,
+                    (
+                        <frame_system::Module<T>>::block_number(),
+                        BalanceOf::<T>::zero(),
+                    ),
+                );
+            }
+            spent.saturating_add(amount) <= cap
+        } else {
+            true
+        }
+    }
+    /// The bank's spend cap and what it has spent in the current window, or `None`
+    /// if uncapped, with whether the recorded window has passed and a new one is due
+    fn spend_cap_usage(
+        bank_id: T::BankId,
+    ) -> Option<(BalanceOf<T>, BalanceOf<T>, bool)> {
+        <SpendCapPerWindow<T>>::get(bank_id).map(|(cap, window)| {
+            let (start, spent) = <SpendWindowUsage<T>>::get(bank_id);
+            if <frame_system::Module<T>>::block_number() >= start + window {
+                (cap, Zero::zero(), true)
+            } else {
+                (cap, spent, false)
+            }
+        })
+    }
+    /// Adds an executed transfer to the bank's usage of its spend cap
+    fn record_capped_spend(bank_id: T::BankId, amount: BalanceOf<T>) {
+        if <SpendCapPerWindow<T>>::contains_key(bank_id) {
+            <SpendWindowUsage<T>>::mutate(bank_id, |(_, spent)| {
+                *spent = spent.saturating_add(amount)
+            });
+        }
+    }
+    /// Clears the vote's start block and credits everyone who voted
+    fn close_vote(bank_id: T::BankId, vote_id: T::VoteId) {
+        <VoteStarted<T>>::remove(vote_id);
+        T::VoteMachine::voters(vote_id).into_iter().for_each(|who| {
+            <MemberStats<T>>::mutate(bank_id, who, |s| {
+                s.votes_cast = s.votes_cast.saturating_add(1)
+            })
+        });
+    }
+    /// Credits the proposer of a spend that was approved
+    fn credit_passed_spend(bank_id: T::BankId, spend_id: T::SpendId) {
+        if let Some(prop) = <SpendProps<T>>::get(bank_id, spend_id) {
+            <MemberStats<T>>::mutate(bank_id, prop.proposer(), |s| {
+                s.spends_passed = s.spends_passed.saturating_add(1)
+            });
+        }
+    }
+    /// True if the vote has been live for longer than the bank's max vote age
+    fn vote_expired(bank_id: T::BankId, vote_id: T::VoteId) -> bool {
+        if let (Some(max_age), Some(started)) = (
+            <MaxVoteAge<T>>::get(bank_id),
+            <VoteStarted<T>>::get(vote_id),
+        ) {
+            <frame_system::Module<T>>::block_number().saturating_sub(started)
+                > max_age
+        } else {
+            false
+        }
+    }
+    /// True if polling the vote would move its proposal out of `Voting`
+    fn vote_concluded(bank_id: T::BankId, vote_id: T::VoteId) -> bool {
+        match T::VoteMachine::get_vote_outcome(vote_id) {
+            Ok(VoteOutcome::Approved) | Ok(VoteOutcome::Rejected) => true,
+            _ => Self::vote_expired(bank_id, vote_id),
+        }
+    }
+    /// Reads up to `max` active lists and proposals across all banks, polling
+    /// those with concluded votes, and returns the number advanced and whether
+    /// the scan stopped before reaching the end
+    fn poke_concluded_proposals(
+        keeper: &T::AccountId,
+        max: u32,
+    ) -> (u32, bool) {
+        let mut resolved = (Vec::new(), Vec::new(), Vec::new());
+        let outcome =
+            Self::scan_concluded_proposals(keeper, max, &mut resolved);
+        // deactivated after the scan so the active sets are not altered mid-iteration
+        let (spends, members, kicks) = resolved;
+        for (bank_id, spend_id) in spends {
+            Self::deactivate_spend(bank_id, spend_id);
+        }
+        for (bank_id, prop_id) in members {
+            Self::deactivate_member(bank_id, prop_id);
+        }
+        for (bank_id, kick_id) in kicks {
+            Self::deactivate_kick(bank_id, kick_id);
+        }
+        outcome
+    }
+    /// Scan for `poke_concluded_proposals`, collecting the proposals it resolves
+    fn scan_concluded_proposals(
+        keeper: &T::AccountId,
+        max: u32,
+        resolved: &mut (
+            Vec<(T::BankId, T::SpendId)>,
+            Vec<(T::BankId, T::MemId)>,
+            Vec<(T::BankId, T::MemId)>,
+        ),
+    ) -> (u32, bool) {
+        let (mut advanced, mut scanned) = (0u32, 0u32);
+        for (bank_id, spend_ids) in <ActiveSpendProps<T>>::iter() {
+            if scanned >= max {
+                return (advanced, true)
+            }
+            scanned += 1;
+            for spend_id in spend_ids {
+                if scanned >= max {
+                    return (advanced, true)
+                }
+                scanned += 1;
+                let prop = match <SpendProps<T>>::get(bank_id, spend_id) {
+                    Some(prop) => prop,
+                    None => continue,
+                };
+                if let SpendState::Voting(vote_id) = prop.state() {
+                    if Self::vote_concluded(bank_id, vote_id) {
+                        if let Ok((state, tally)) =
+                            Self::poll_and_tally_spend(prop)
+                        {
+                            Self::deposit_event(RawEvent::SpendProposalPolled(
+                                bank_id,
+                                spend_id,
+                                state,
+                                tally,
+                                ResolutionTrigger::Member(keeper.clone()),
+                            ));
+                            if !Self::spend_is_live(bank_id, spend_id, state) {
+                                resolved.0.push((bank_id, spend_id));
+                            }
+                            advanced += 1;
+                        }
+                    }
+                }
+            }
+        }
+        for (bank_id, prop_ids) in <ActiveMemberProps<T>>::iter() {
+            if scanned >= max {
+                return (advanced, true)
+            }
+            scanned += 1;
+            for prop_id in prop_ids {
+                if scanned >= max {
+                    return (advanced, true)
+                }
+                scanned += 1;
+                let prop = match <MemberProps<T>>::get(bank_id, prop_id) {
+                    Some(prop) => prop,
+                    None => continue,
+                };
+                if let ProposalState::Voting(vote_id) = prop.state() {
+                    if Self::vote_concluded(bank_id, vote_id) {
+                        if let Ok((state, tally)) =
+                            Self::poll_and_tally_member(prop)
+                        {
+                            Self::deposit_event(
+                                RawEvent::MemberProposalPolled(
+                                    bank_id,
+                                    prop_id,
+                                    state,
+                                    tally,
+                                    ResolutionTrigger::Member(keeper.clone()),
+                                ),
+                            );
+                            if !Self::proposal_is_live(state) {
+                                resolved.1.push((bank_id, prop_id));
+                            }
+                            advanced += 1;
+                        }
+                    }
+                }
+            }
+        }
+        for (bank_id, kick_ids) in <ActiveKickProps<T>>::iter() {
+            if scanned >= max {
+                return (advanced, true)
+            }
+            scanned += 1;
+            for kick_id in kick_ids {
+                if scanned >= max {
+                    return (advanced, true)
+                }
+                scanned += 1;
+                let prop = match <KickProps<T>>::get(bank_id, kick_id) {
+                    Some(prop) => prop,
+                    None => continue,
+                };
+                if let ProposalState::Voting(vote_id) = prop.state() {
+                    if Self::vote_concluded(bank_id, vote_id) {
+                        if let Ok((state, tally)) =
+                            Self::poll_and_tally_kick(prop)
+                        {
+                            Self::deposit_event(RawEvent::KickProposalPolled(
+                                bank_id,
+                                kick_id,
+                                state,
+                                tally,
+                                ResolutionTrigger::Member(keeper.clone()),
+                            ));
+                            if !Self::proposal_is_live(state) {
+                                resolved.2.push((bank_id, kick_id));
+                            }
+                            advanced += 1;
+                        }
+                    }
+                }
+            }
+        }
+        (advanced, false)
+    }
+    /// True while polling may still move a proposal in this state along
+    fn spend_is_live(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        state: SpendState<T::VoteId>,
+    ) -> bool {
+        match state {
+            // conditional spends are retried by polling until they execute and
+            // recurring spends stay live until their final installment
+            SpendState::ApprovedButNotExecuted => {
+                <SpendConditions<T>>::contains_key(bank_id, spend_id)
+                    || <RecurringSpends<T>>::contains_key(bank_id, spend_id)
+                    || <CapDeferredSpends<T>>::get(bank_id, spend_id)
+            }
+            _ => !state.is_terminal(),
+        }
+    }
+    fn proposal_is_live(state: ProposalState<T::VoteId>) -> bool {
+        !state.is_terminal()
+    }
+    /// True if any spend, membership or kick proposal of the bank is unresolved
+    fn has_live_proposals(bank_id: T::BankId) -> bool {
+        <SpendProps<T>>::iter_prefix(bank_id).any(|(spend_id, prop)| {
+            Self::spend_is_live(bank_id, spend_id, prop.state())
+        }) || <MemberProps<T>>::iter_prefix_values(bank_id)
+            .any(|prop| Self::proposal_is_live(prop.state()))
+            || <KickProps<T>>::iter_prefix_values(bank_id)
+                .any(|prop| Self::proposal_is_live(prop.state()))
+    }
+    /// Queues the active spend proposals of the banks scheduled at block `n` that
+    /// are not already queued and reschedules those banks, returning the weight consumed
+    fn queue_active_spends(n: T::BlockNumber) -> Weight {
+        let banks = <SpendPollsDue<T>>::take(n);
+        let (mut reads, mut writes) = (1u64, 1u64);
+        if banks.is_empty() {
+            return T::DbWeight::get().reads_writes(reads, writes)
+        }
+        let mut queue = <SpendPollQueue<T>>::get();
+        reads += 1;
+        let mut queued = false;
+        for bank_id in banks {
+            // bank, frequency override and global frequency
+            reads += 3;
+            if !<BankStores<T>>::contains_key(bank_id) {
+                continue
+            }
+            if Self::is_poll_due(n, Self::spend_poll_frequency_of(bank_id)) {
+                let spend_ids = <ActiveSpendProps<T>>::get(bank_id);
+                reads += 1 + spend_ids.len() as u64;
+                for id in spend_ids {
+                    if !<SpendQueuedForPoll<T>>::get(bank_id, id) {
+                        <SpendQueuedForPoll<T>>::insert(bank_id, id, true);
+                        queue.push((bank_id, id));
+                        queued = true;
+                        writes += 1;
+                    }
+                }
+            }
+            // a frequency changed since scheduling moves the bank onto the new cadence
+            Self::schedule_spend_poll(bank_id, n);
+            reads += 1;
+            writes += 1;
+        }
+        if queued {
+            <SpendPollQueue<T>>::put(queue);
+            writes += 1;
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Queues the active membership and kick proposals of the banks scheduled at
+    /// block `n` and reschedules those banks, returning the weight consumed
+    fn queue_active_members(n: T::BlockNumber) -> Weight {
+        let banks = <MemberPollsDue<T>>::take(n);
+        let (mut reads, mut writes) = (1u64, 1u64);
+        if banks.is_empty() {
+            return T::DbWeight::get().reads_writes(reads, writes)
+        }
+        let mut member_queue = <MemberPollQueue<T>>::get();
+        let mut kick_queue = <KickPollQueue<T>>::get();
+        reads += 2;
+        let (mut members_queued, mut kicks_queued) = (false, false);
+        for bank_id in banks {
+            reads += 3;
+            if !<BankStores<T>>::contains_key(bank_id) {
+                continue
+            }
+            if Self::is_poll_due(n, Self::member_poll_frequency_of(bank_id)) {
+                let prop_ids = <ActiveMemberProps<T>>::get(bank_id);
+                let kick_ids = <ActiveKickProps<T>>::get(bank_id);
+                reads += 2 + prop_ids.len() as u64 + kick_ids.len() as u64;
+                for id in prop_ids {
+                    if !<MemberQueuedForPoll<T>>::get(bank_id, id) {
+                        <MemberQueuedForPoll<T>>::insert(bank_id, id, true);
+                        member_queue.push((bank_id, id));
+                        members_queued = true;
+                        writes += 1;
+                    }
+                }
+                for id in kick_ids {
+                    if !<KickQueuedForPoll<T>>::get(bank_id, id) {
+                        <KickQueuedForPoll<T>>::insert(bank_id, id, true);
+                        kick_queue.push((bank_id, id));
+                        kicks_queued = true;
+                        writes += 1;
+                    }
+                }
+            }
+            Self::schedule_member_poll(bank_id, n);
+            reads += 1;
+            writes += 1;
+        }
+        if members_queued {
+            <MemberPollQueue<T>>::put(member_queue);
+            writes += 1;
+        }
+        if kicks_queued {
+            <KickPollQueue<T>>::put(kick_queue);
+            writes += 1;
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Schedules the bank's spend polls at the first block after `n` due under its
+    /// current frequency, or not at all at frequency zero
+    fn schedule_spend_poll(bank_id: T::BankId, n: T::BlockNumber) {
+        let frequency = Self::spend_poll_frequency_of(bank_id);
+        if !frequency.is_zero() {
+            <SpendPollsDue<T>>::mutate(
+                Self::next_poll_due(n, frequency),
+                |banks| {
+                    if !banks.contains(&bank_id) {
+                        banks.push(bank_id);
+                    }
+                },
+            );
+        }
+    }
+    /// Schedules the bank's membership and kick polls at the first block after `n`
+    /// due under its current frequency, or not at all at frequency zero
+    fn schedule_member_poll(bank_id: T::BankId, n: T::BlockNumber) {
+        let frequency = Self::member_poll_frequency_of(bank_id);
+        if !frequency.is_zero() {
+            <MemberPollsDue<T>>::mutate(
+                Self::next_poll_due(n, frequency),
+                |banks| {
+                    if !banks.contains(&bank_id) {
+                        banks.push(bank_id);
+                    }
+                },
+            );
+        }
+    }
+    /// The first block after `n` that is a multiple of the non-zero `frequency`
+    fn next_poll_due(
+        n: T::BlockNumber,
+        frequency: T::BlockNumber,
+    ) -> T::BlockNumber {
+        n - n % frequency + frequency
+    }
+    /// Polling at frequency zero is skipped rather than dividing by zero
+    fn is_poll_due(n: T::BlockNumber, frequency: T::BlockNumber) -> bool {
+        !frequency.is_zero() && (n % frequency).is_zero()
+    }
+    /// The bank's spend poll frequency, or the global default if it has no override
+    pub fn spend_poll_frequency_of(bank_id: T::BankId) -> T::BlockNumber {
+        <BankSpendPollFrequency<T>>::get(bank_id)
+            .unwrap_or_else(Self::spend_poll_frequency)
+    }
+    /// The bank's membership poll frequency, or the global default if it has no override
+    pub fn member_poll_frequency_of(bank_id: T::BankId) -> T::BlockNumber {
+        <BankMemberPollFrequency<T>>::get(bank_id)
+            .unwrap_or_else(Self::member_poll_frequency)
+    }
+    /// Polls up to `budget` queued proposals, spends first, returning the weight consumed
+    fn drain_poll_queues(budget: u32) -> Weight {
+        let db = T::DbWeight::get();
+        let mut budget = budget as usize;
+        let mut weight = db.reads(3);
+        let mut spends = <SpendPollQueue<T>>::get();
+        if !spends.is_empty() && budget > 0 {
+            let n = budget.min(spends.len());
+            for (bank_id, spend_id) in spends.drain(..n) {
+                <SpendQueuedForPoll<T>>::remove(bank_id, spend_id);
+                weight = weight
+                    .saturating_add(Self::poll_queued_spend(bank_id, spend_id))
+                    .saturating_add(db.writes(1));
+            }
+            budget -= n;
+            <SpendPollQueue<T>>::put(spends);
+            weight = weight.saturating_add(db.writes(1));
+        }
+        let mut members = <MemberPollQueue<T>>::get();
+        if !members.is_empty() && budget > 0 {
+            let n = budget.min(members.len());
+            for (bank_id, prop_id) in members.drain(..n) {
+                <MemberQueuedForPoll<T>>::remove(bank_id, prop_id);
+                weight = weight
+                    .saturating_add(Self::poll_queued_member(bank_id, prop_id))
+                    .saturating_add(db.writes(1));
+            }
+            budget -= n;
+            <MemberPollQueue<T>>::put(members);
+            weight = weight.saturating_add(db.writes(1));
+        }
+        let mut kicks = <KickPollQueue<T>>::get();
+        if !kicks.is_empty() && budget > 0 {
+            let n = budget.min(kicks.len());
+            for (bank_id, kick_id) in kicks.drain(..n) {
+                <KickQueuedForPoll<T>>::remove(bank_id, kick_id);
+                weight = weight
+                    .saturating_add(Self::poll_queued_kick(bank_id, kick_id))
+                    .saturating_add(db.writes(1));
+            }
+            <KickPollQueue<T>>::put(kicks);
+            weight = weight.saturating_add(db.writes(1));
+        }
+        weight
+    }
+    /// Polls a queued spend proposal, dropping it from the bank's active set
+    /// once resolved or no longer stored, and returns the weight consumed
+    fn poll_queued_spend(bank_id: T::BankId, spend_id: T::SpendId) -> Weight {
+        let db = T::DbWeight::get();
+        let prop = if let Some(prop) = <SpendProps<T>>::get(bank_id, spend_id) {
+            prop
+        } else {
+            Self::deactivate_spend(bank_id, spend_id);
+            return db.reads_writes(2, 1)
+        };
+        let mut weight = db.reads_writes(1, 1);
+        if let SpendState::Voting(_) = prop.state() {
+            // vote outcome
+            weight = weight.saturating_add(db.reads(1));
+        }
+        // kept active on error so it is polled again next pass
+        if let Ok((state, tally)) = Self::poll_and_tally_spend(prop) {
+            Self::deposit_event(RawEvent::SpendProposalPolled(
+                bank_id,
+                spend_id,
+                state,
+                tally,
+                ResolutionTrigger::Auto,
+            ));
+            if !Self::spend_is_live(bank_id, spend_id, state) {
+                Self::deactivate_spend(bank_id, spend_id);
+                weight = weight.saturating_add(db.reads_writes(1, 1));
+            }
+        }
+        weight
+    }
+    fn poll_queued_member(bank_id: T::BankId, prop_id: T::MemId) -> Weight {
+        let db = T::DbWeight::get();
+        let prop = if let Some(prop) = <MemberProps<T>>::get(bank_id, prop_id) {
+            prop
+        } else {
+            Self::deactivate_member(bank_id, prop_id);
+            return db.reads_writes(2, 1)
+        };
+        let mut weight = db.reads_writes(1, 1);
+        if let ProposalState::Voting(_) = prop.state() {
+            weight = weight.saturating_add(db.reads(1));
+        }
+        if let Ok((state, tally)) = Self::poll_and_tally_member(prop) {
+            Self::deposit_event(RawEvent::MemberProposalPolled(
+                bank_id,
+                prop_id,
+                state,
+                tally,
+                ResolutionTrigger::Auto,
+            ));
+            if !Self::proposal_is_live(state) {
+                Self::deactivate_member(bank_id, prop_id);
+                weight = weight.saturating_add(db.reads_writes(1, 1));
+            }
+        }
+        weight
+    }
+    fn poll_queued_kick(bank_id: T::BankId, kick_id: T::MemId) -> Weight {
+        let db = T::DbWeight::get();
+        let prop = if let Some(prop) = <KickProps<T>>::get(bank_id, kick_id) {
+            prop
+        } else {
+            Self::deactivate_kick(bank_id, kick_id);
+            return db.reads_writes(2, 1)
+        };
+        let mut weight = db.reads_writes(1, 1);
+        if let ProposalState::Voting(_) = prop.state() {
+            weight = weight.saturating_add(db.reads(1));
+        }
+        if let Ok((state, tally)) = Self::poll_and_tally_kick(prop) {
+            Self::deposit_event(RawEvent::KickProposalPolled(
+                bank_id,
+                kick_id,
+                state,
+                tally,
+                ResolutionTrigger::Auto,
+            ));
+            if !Self::proposal_is_live(state) {
+                Self::deactivate_kick(bank_id, kick_id);
+                weight = weight.saturating_add(db.reads_writes(1, 1));
+            }
+        }
+        weight
+    }
+    /// Polls the spend proposal, recording the tally of a vote the poll concludes
+    fn poll_and_tally_spend(
+        prop: SpendProp<T>,
+    ) -> Result<
+        (SpendState<T::VoteId>, Option<(T::Signal, T::Signal)>),
+        DispatchError,
+    > {
+        let (bank_id, spend_id) = (prop.bank_id(), prop.spend_id());
+        let live_vote = match prop.state() {
+            SpendState::Voting(vote_id) => Some(vote_id),
+            _ => None,
+        };
+        let state = Self::poll_spend_proposal(prop)?;
+        let tally = match live_vote {
+            Some(vote_id) if !matches!(state, SpendState::Voting(_)) => {
+                T::VoteMachine::tally(vote_id)
+            }
+            _ => None,
+        };
+        if let Some(tally) = tally {
+            <SpendTallies<T>>::insert(bank_id, spend_id, tally);
+        }
+        Ok((state, tally))
+    }
+    /// Polls the membership proposal, recording the tally of a vote the poll concludes
+    fn poll_and_tally_member(
+        prop: MemberProp<T>,
+    ) -> Result<
+        (ProposalState<T::VoteId>, Option<(T::Signal, T::Signal)>),
+        DispatchError,
+    > {
+        let (bank_id, prop_id) = (prop.bank_id(), prop.prop_id());
+        let live_vote = match prop.state() {
+            ProposalState::Voting(vote_id) => Some(vote_id),
+            _ => None,
+        };
+        let state = Self::poll_membership_proposal(prop)?;
+        let tally = match live_vote {
+            Some(vote_id) if !matches!(state, ProposalState::Voting(_)) => {
+                T::VoteMachine::tally(vote_id)
+            }
+            _ => None,
+        };
+        if let Some(tally) = tally {
+            <MemberTallies<T>>::insert(bank_id, prop_id, tally);
+        }
+        Ok((state, tally))
+    }
+    /// Polls the kick proposal, returning the tally of a vote the poll concludes
+    fn poll_and_tally_kick(
+        prop: KickProp<T>,
+    ) -> Result<
+        (ProposalState<T::VoteId>, Option<(T::Signal, T::Signal)>),
+        DispatchError,
+    > {
+        let live_vote = match prop.state() {
+            ProposalState::Voting(vote_id) => Some(vote_id),
+            _ => None,
+        };
+        let state = Self::poll_kick_proposal(prop)?;
+        let tally = match live_vote {
+            Some(vote_id) if !matches!(state, ProposalState::Voting(_)) => {
+                T::VoteMachine::tally(vote_id)
+            }
+            _ => None,
+        };
+        Ok((state, tally))
+    }
+    fn deactivate_spend(bank_id: T::BankId, spend_id: T::SpendId) {
+        Self::log_activity(bank_id, BankActivity::SpendResolved(spend_id));
+        Self::release_open_proposal(
+            bank_id,
+            <SpendOpenedBy<T>>::take(bank_id, spend_id),
+        );
+        let mut ids = <ActiveSpendProps<T>>::get(bank_id);
+        ids.retain(|id| *id != spend_id);
+        if ids.is_empty() {
+            <ActiveSpendProps<T>>::remove(bank_id);
+        } else {
+            <ActiveSpendProps<T>>::insert(bank_id, ids);
+        }
+    }
+    /// Checks the org's bank cap, membership and name before opening the bank
+    fn summon_bank(
+        opener: &T::AccountId,
+        org: T::OrgId,
+        deposit: BalanceOf<T>,
+        controller: Option<T::AccountId>,
+        threshold: Threshold<T>,
+        name: Option<Vec<u8>>,
+    ) -> Result<T::BankId, DispatchError> {
+        // distinguishes a missing org from a caller who is not a member
+        ensure!(
+            <org::Module<T>>::org_exists(org),
+            Error::<T>::OrgDoesNotExist
+        );
+        ensure!(
+            <OrgBankCount<T>>::get(org) < T::MaxBanksPerOrg::get(),
+            Error::<T>::CannotOpenBankAccountForOrgIfBankCountExceedsLimitPerOrg
+        );
+        let permitted = if T::RequireSupervisorToSummon::get() {
+            <org::Module<T>>::is_organization_supervisor(org, opener)
+        } else {
+            <org::Module<T>>::is_member_of_group(org, opener)
+        };
+        ensure!(permitted, Error::<T>::NotPermittedToOpenBankAccountForOrg);
+        Self::ensure_bank_name(&name)?;
+        let bank_id = Self::open_bank_account(
+            opener.clone(),
+            org,
+            deposit,
+            controller.clone(),
+            threshold,
+        )?;
+        if name.is_some() {
+            <BankStores<T>>::mutate(bank_id, |b| {
+                *b = b.as_ref().map(|b| b.set_name(name.clone()))
+            });
+        }
+        <OrgBankCount<T>>::mutate(org, |count| *count += 1);
+        Self::deposit_event(RawEvent::AccountOpened(
+            opener.clone(),
+            bank_id,
+            deposit,
+            org,
+            controller,
+            name,
+        ));
+        Ok(bank_id)
+    }
+    /// Appends to the bank's activity log, dropping the oldest entries past the cap
+    fn log_activity(bank_id: T::BankId, activity: Activity<T>) {
+        let now = <frame_system::Module<T>>::block_number();
+        <BankActivityLog<T>>::mutate(bank_id, |log| {
+            log.push((now, activity));
+            let excess =
+                log.len().saturating_sub(T::MaxActivityLog::get() as usize);
+            log.drain(..excess);
+        });
+    }
+    fn deactivate_member(bank_id: T::BankId, prop_id: T::MemId) {
+        Self::release_open_proposal(
+            bank_id,
+            <MemberOpenedBy<T>>::take(bank_id, prop_id),
+        );
+        let mut ids = <ActiveMemberProps<T>>::get(bank_id);
+        ids.retain(|id| *id != prop_id);
+        if ids.is_empty() {
+            <ActiveMemberProps<T>>::remove(bank_id);
+        } else {
+            <ActiveMemberProps<T>>::insert(bank_id, ids);
+        }
+    }
+    /// Ensures `who` may open another proposal in the bank
+    fn ensure_below_open_proposal_cap(
+        bank_id: T::BankId,
+        who: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            <OpenProposalCount<T>>::get(bank_id, who)
+                < T::MaxOpenProposalsPerMember::get(),
+            Error::<T>::TooManyOpenProposals
+        );
+        Ok(())
+    }
+    /// Counts the membership or kick proposal against `opener` until it is deactivated
+    fn track_open_proposal(
+        bank_id: T::BankId,
+        id: T::MemId,
+        opener: &T::AccountId,
+    ) {
+        <MemberOpenedBy<T>>::insert(bank_id, id, opener);
+        <OpenProposalCount<T>>::mutate(bank_id, opener, |count| *count += 1);
+    }
+    /// Frees the slot of a deactivated proposal for the member who opened it
+    fn release_open_proposal(bank_id: T::BankId, opener: Option<T::AccountId>) {
+        if let Some(who) = opener {
+            <OpenProposalCount<T>>::mutate(bank_id, &who, |count| {
+                *count = count.saturating_sub(1)
+            });
+        }
+    }
+    fn deactivate_kick(bank_id: T::BankId, kick_id: T::MemId) {
+        Self::release_open_proposal(
+            bank_id,
+            <MemberOpenedBy<T>>::take(bank_id, kick_id),
+        );
+        let mut ids = <ActiveKickProps<T>>::get(bank_id);
+        ids.retain(|id| *id != kick_id);
+        if ids.is_empty() {
+            <ActiveKickProps<T>>::remove(bank_id);
+        } else {
+            <ActiveKickProps<T>>::insert(bank_id, ids);
+        }
+    }
+    /// Returns false if turnout for the vote is below the bank's quorum
+    fn vote_met_quorum(
+        bank_id: T::BankId,
+        vote_id: T::VoteId,
+    ) -> Result<bool, DispatchError> {
+        if let Some(quorum) = <BankQuorum<T>>::get(bank_id) {
+            let (turnout, all_possible_turnout) =
+                T::VoteMachine::turnout(vote_id)
+                    .ok_or(Error::<T>::CannotPollProposalIfVoteDNE)?;
+            Ok(quorum.mul_ceil(all_possible_turnout) <= turnout)
+        } else {
+            Ok(true)
+        }
+    }
+    /// Returns the bank if the caller is its controller
+    fn ensure_controller(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+    ) -> Result<BankSt<T>, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotConfigureBankThatDNE)?;
+        ensure!(
+            bank.is_controller(caller),
+            Error::<T>::NotPermittedToConfigureBankAccount
+        );
+        Ok(bank)
+    }
+    fn ensure_can_process(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+    ) -> DispatchResult {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotProcessProposalIfBankDNE)?;
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), caller),
+            Error::<T>::MustBeMemberToProcessProposal
+        );
+        Ok(())
+    }
+    /// Pays the processing reward if the bank can cover it, returning the amount paid
+    fn pay_processing_reward(
+        bank_id: T::BankId,
+        processor: &T::AccountId,
+    ) -> BalanceOf<T> {
+        let reward = T::ProcessingReward::get();
+        if !reward.is_zero()
+            && <T as Trait>::Currency::transfer(
+                &Self::bank_account_id(bank_id),
+                processor,
+                reward,
+                ExistenceRequirement::KeepAlive,
+            )
+            .is_ok()
+        {
+            reward
+        } else {
+            Zero::zero()
+        }
+    }
+    /// The bank's controller or its org's supervisor may freeze and unfreeze it
+    fn ensure_can_freeze(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+    ) -> Result<BankSt<T>, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotConfigureBankThatDNE)?;
+        ensure!(
+            bank.is_controller(caller)
+                || <org::Module<T>>::is_organization_supervisor(
+                    bank.org(),
+                    caller
+                ),
+            Error::<T>::NotPermittedToFreezeBank
+        );
+        Ok(bank)
+    }
+    /// Registers a threshold for the bank's votes, which must be for the
+    /// bank's org and require some support
+    fn register_bank_threshold(
+        bank: &BankSt<T>,
+        threshold: Threshold<T>,
+    ) -> Result<T::ThresholdId, DispatchError> {
+        ensure!(
+            threshold.org().org() == bank.org(),
+            Error::<T>::ThresholdCannotBeSetForOrg
+        );
+        let requires_support = match threshold.threshold() {
+            XorThreshold::Signal(t) => !t.in_favor().is_zero(),
+            XorThreshold::Percent(t) => !t.in_favor().is_zero(),
+        };
+        ensure!(requires_support, Error::<T>::ThresholdMustRequireSupport);
+        T::VoteMachine::register_threshold(threshold)
+    }
+    /// Threshold the bank's membership votes are dispatched with by default
+    fn member_threshold_id(bank: &BankSt<T>) -> T::ThresholdId {
+        <MemberThreshold<T>>::get(bank.id())
+            .unwrap_or_else(|| bank.threshold_id())
+    }
+    /// Ensures a proposal's threshold is at least as strict as the bank's for
+    /// membership votes: the same org representation, no less support and no
+    /// more opposition tolerated before rejecting
+    fn ensure_member_threshold_override(
+        bank_id: T::BankId,
+        threshold: &Threshold<T>,
+    ) -> DispatchResult {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::BankMustExistToProposeFrom)?;
+        let base = T::VoteMachine::threshold(Self::member_threshold_id(&bank))
+            .ok_or(Error::<T>::MemberThresholdOverrideTooWeak)?;
+        let no_weaker = threshold.org() == base.org()
+            && match (threshold.threshold(), base.threshold()) {
+                (XorThreshold::Signal(t), XorThreshold::Signal(b)) => {
+                    Self::is_no_weaker(&t, &b)
+                }
+                (XorThreshold::Percent(t), XorThreshold::Percent(b)) => {
+                    Self::is_no_weaker(&t, &b)
+                }
+                _ => false,
+            };
+        ensure!(no_weaker, Error::<T>::MemberThresholdOverrideTooWeak);
+        Ok(())
+    }
+    fn is_no_weaker<S: Copy + PartialOrd>(
+        t: &VoteThreshold<S>,
+        base: &VoteThreshold<S>,
+    ) -> bool {
+        t.in_favor() >= base.in_favor()
+            && match (t.against(), base.against()) {
+                (_, None) => true,
+                (Some(t), Some(b)) => t <= b,
+                (None, Some(_)) => false,
+            }
+    }
+    fn ensure_bank_name(name: &Option<Vec<u8>>) -> DispatchResult {
+        if let Some(n) = name {
+            ensure!(
+                n.len() <= T::MaxBankNameLen::get() as usize,
+                Error::<T>::BankNameTooLong
+            );
+        }
+        Ok(())
+    }
+    /// All banks opened by the org in ascending id order
+    pub fn get_org_banks(
+        org: T::OrgId,
+    ) -> Result<Vec<T::BankId>, DispatchError> {
+        let mut banks = <BankStores<T>>::iter()
+            .filter(|(_, bank_state)| bank_state.org() == org)
+            .map(|(bank_id, _)| bank_id)
+            .collect::<Vec<_>>();
+        ensure!(!banks.is_empty(), Error::<T>::NoBanksForOrg);
+        // storage iterates in hashed key order, not id order
+        banks.sort();
+        Ok(banks)
+    }
+    /// The org's lowest bank id, kept for callers that expect a single bank
+    #[deprecated(note = "orgs may open several banks, use `get_org_banks`")]
+    pub fn get_org_bank(org: T::OrgId) -> Result<T::BankId, DispatchError> {
+        Self::get_org_banks(org).map(|banks| banks[0])
+    }
+    /// Page of the bank's members (ordered by account) paired with their shares
+    pub fn bank_members(
+        bank_id: T::BankId,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(T::AccountId, T::Shares)> {
+        if let Some(bank) = <BankStores<T>>::get(bank_id) {
+            if let Some(group) = <org::Module<T>>::get_group(bank.org()) {
+                return group
+                    .0
+                    .into_iter()
+                    .skip(start as usize)
+                    .take(limit as usize)
+                    .filter_map(|acc| {
+                        <org::Module<T>>::members(bank.org(), &acc)
+                            .map(|profile| (acc, profile.total()))
+                    })
+                    .collect()
+            }
+        }
+        Vec::new()
+    }
+    /// Outstanding shares of the bank's org and the bank's cap on them
+    pub fn bank_shares(
+        bank_id: T::BankId,
+    ) -> Option<(T::Shares, Option<T::Shares>)> {
+        <BankStores<T>>::get(bank_id).map(|bank| {
+            (
+                <org::Module<T>>::outstanding_shares(bank.org()),
+                <MaxTotalShares<T>>::get(bank_id),
+            )
+        })
+    }
+    /// Dry run of the checks executing the spend makes, returning why it would
+    /// not be paid if executed now
+    pub fn can_execute_spend(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+    ) -> DispatchResult {
+        let prop = <SpendProps<T>>::get(bank_id, spend_id)
+            .ok_or(Error::<T>::CannotExecuteSpendThatDNE)?;
+        ensure!(
+            !matches!(
+                prop.state(),
+                SpendState::ApprovedAndExecuted
+                    | SpendState::Rejected(_)
+                    | SpendState::Expired
+            ),
+            Error::<T>::CannotExecuteResolvedSpend
+        );
+        ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
+        if let Some(condition) = <SpendConditions<T>>::get(bank_id, spend_id) {
+            ensure!(
+                Self::condition_holds(condition),
+                Error::<T>::SpendConditionNotMet
+            );
+        }
+        if let Some(to_bank) = <InterBankSpends<T>>::get(bank_id, spend_id) {
+            ensure!(
+                Self::is_bank(to_bank),
+                Error::<T>::CannotSpendToBankThatDNE
+            );
+        }
+        let due = <SplitPayouts<T>>::get(bank_id, spend_id)
+            .map(|payouts| {
+                payouts
+                    .iter()
+                    .fold(BalanceOf::<T>::zero(), |acc, (_, amt)| {
+                        acc.saturating_add(*amt)
+                    })
+            })
+            .unwrap_or_else(|| prop.amount());
+        if let Some((cap, spent, _)) = Self::spend_cap_usage(bank_id) {
+            ensure!(
+                spent.saturating_add(due) <= cap,
+                Error::<T>::SpendWouldExceedSpendCap
+            );
+        }
+        // funds reserved for this spend are released before it is paid
+        let available = Self::bank_free_balance(bank_id).saturating_add(
+            <SpendReservations<T>>::get(bank_id, spend_id)
+                .unwrap_or_else(Zero::zero),
+        );
+        // transfers keep the bank alive and dividends keep the minimum deposit
+        let floor = if <DividendSpends<T>>::get(bank_id, spend_id) {
+            T::MinDeposit::get()
+        } else {
+            <T as Trait>::Currency::minimum_balance()
+        };
+        ensure!(
+            available >= due.saturating_add(floor),
+            Error::<T>::InsufficientBankBalanceToExecuteSpend
+        );
+        // locks on the bank account are checked with the reservation released
+        with_transaction(|| {
+            Self::release_spend_funds(bank_id, spend_id);
+            TransactionOutcome::Rollback(Self::ensure_bank_can_withdraw(
+                bank_id, due,
+            ))
+        })
+    }
+    /// State, balances, live proposals and configuration of the bank in one read
+    pub fn bank_snapshot(
+        bank_id: T::BankId,
+    ) -> Option<
+        BankSnapshot<
+            BankSt<T>,
+            T::SpendId,
+            T::MemId,
+            T::ThresholdId,
+            BalanceOf<T>,
+            T::BlockNumber,
+        >,
+    > {
+        let bank = <BankStores<T>>::get(bank_id)?;
+        let spend_threshold = <SpendThreshold<T>>::get(bank_id)
+            .unwrap_or_else(|| bank.threshold_id());
+        let member_threshold = Self::member_threshold_id(&bank);
+        Some(BankSnapshot {
+            bank,
+            balance: Self::bank_balance(bank_id),
+            free: Self::bank_free_balance(bank_id),
+            reserved: Self::bank_reserved_balance(bank_id),
+            available: Self::bank_available_to_spend(bank_id),
+            active_spends: <ActiveSpendProps<T>>::get(bank_id),
+            active_member_proposals: <ActiveMemberProps<T>>::get(bank_id),
+            spend_threshold,
+            member_threshold,
+            spend_poll_frequency: Self::spend_poll_frequency_of(bank_id),
+            member_poll_frequency: Self::member_poll_frequency_of(bank_id),
+        })
+    }
+    /// Cap on the shares a single member of the bank's org may hold
+    pub fn max_shares_per_member_of(bank_id: T::BankId) -> T::Shares {
+        <BankMaxSharesPerMember<T>>::get(bank_id)
+            .unwrap_or_else(T::MaxSharesPerMember::get)
+    }
+    /// Shares held by `who` over the outstanding shares of the bank's org,
+    /// zero for non-members and for banks or orgs that hold no shares
+    pub fn member_ownership(bank_id: T::BankId, who: T::AccountId) -> Permill {
+        Self::member_voting_power(bank_id, who).unwrap_or_else(Permill::zero)
+    }
+    /// Voting weight of `who` in the bank's org, `None` if not a member
+    pub fn member_voting_power(
+        bank_id: T::BankId,
+        who: T::AccountId,
+    ) -> Option<Permill> {
+        let bank = <BankStores<T>>::get(bank_id)?;
+        let profile = <org::Module<T>>::members(bank.org(), &who)?;
+        let total = <org::Module<T>>::outstanding_shares(bank.org());
+        if total.is_zero() {
+            return None
+        }
+        Some(Permill::from_rational_approximation(profile.total(), total))
+    }
+    /// Every member of the bank's org paired with their shares
+    pub fn bank_membership(
+        bank_id: T::BankId,
+    ) -> Vec<(T::AccountId, T::Shares)> {
+        Self::bank_members(bank_id, 0, u32::max_value())
+    }
+    /// True if `who` has voted in a live spend vote of the bank
+    fn has_vote_in_flight(bank_id: T::BankId, who: &T::AccountId) -> bool {
+        <ActiveSpendProps<T>>::get(bank_id)
+            .into_iter()
+            .any(|spend_id| {
+                if let Some(SpendState::Voting(vote_id)) =
+                    <SpendProps<T>>::get(bank_id, spend_id)
+                        .map(|prop| prop.state())
+                {
+                    T::VoteMachine::voters(vote_id).contains(who)
+                } else {
+                    false
+                }
+            })
+    }
+    /// Checks the bank has no more active spend proposals than the caller weighed
+    fn ensure_live_spends_witness(
+        bank_id: T::BankId,
+        live_spends: u32,
+    ) -> DispatchResult {
+        ensure!(
+            <ActiveSpendProps<T>>::get(bank_id).len() <= live_spends as usize,
+            Error::<T>::BankSizeWitnessTooLow
+        );
+        Ok(())
+    }
+    /// Checks the bank has issued no more proposal and request ids than the
+    /// caller weighed, which bounds the proposals stored
+    fn ensure_proposals_witness(
+        bank_id: T::BankId,
+        proposals: u32,
+    ) -> DispatchResult {
+        let issued = <SpendNonceMap<T>>::get(bank_id)
+            .saturated_into::<u32>()
+            .saturating_add(
+                <ProposalNonceMap<T>>::get(bank_id).saturated_into::<u32>(),
+            )
+            .saturating_add(
+                <RequestNonceMap<T>>::get(bank_id).saturated_into::<u32>(),
+            );
+        ensure!(issued <= proposals, Error::<T>::BankSizeWitnessTooLow);
+        Ok(())
+    }
+    /// True if the bank's org has outstanding shares, without which no vote could
+    /// pass and the controller must sudo approve or close the bank instead
+    fn org_has_members(bank: &BankSt<T>) -> bool {
+        !<org::Module<T>>::outstanding_shares(bank.org()).is_zero()
+    }
+    /// True if `who` is a member of the bank's org or the delegate of one, with
+    /// shares that have vested
+    fn can_trigger_votes(bank: &BankSt<T>, who: &T::AccountId) -> bool {
+        Self::has_vested_shares(bank, who)
+            || <Delegators<T>>::get(bank.id(), who)
+                .iter()
+                .any(|m| Self::has_vested_shares(bank, m))
+    }
+    /// Ensures `who` may trigger a vote in the bank
+    fn ensure_can_trigger_vote(
+        bank: &BankSt<T>,
+        who: &T::AccountId,
+    ) -> DispatchResult {
+        ensure!(
+            Self::org_has_members(bank),
+            Error::<T>::CannotTriggerVoteWithNoMembers
+        );
+        ensure!(
+            Self::can_trigger_votes(bank, who),
+            Error::<T>::NotPermittedToTriggerVoteForBankAccount
+        );
+        Ok(())
     }
-}
-
-impl<T: Trait> Module<T> {
-    /// Performs computation so don't call unnecessarily
-    pub fn bank_account_id(id: T::BankId) -> T::AccountId {
-        T::BigBank::get().into_sub_account(id)
-    }
-    pub fn bank_balance(bank: T::BankId) -> BalanceOf<T> {
-        <T as Trait>::Currency::total_balance(&Self::bank_account_id(bank))
-    }
-    pub fn is_bank(id: T::BankId) -> bool {
-        <BankStores<T>>::get(id).is_some()
-    }
-    pub fn is_spend(bank: T::BankId, spend: T::SpendId) -> bool {
-        <SpendProps<T>>::get(bank, spend).is_some()
-    }
-    pub fn is_proposal(bank: T::BankId, proposal: T::MemId) -> bool {
-        <MemberProps<T>>::get(bank, proposal).is_some()
+    fn has_vested_shares(bank: &BankSt<T>, who: &T::AccountId) -> bool {
+        <org::Module<T>>::members(bank.org(), who)
+            .map(|profile| {
+                profile.total() > Self::locked_shares_of(bank.id(), who)
+            })
+            .unwrap_or(false)
     }
-    fn generate_bank_uid() -> T::BankId {
-        let mut bank_nonce_id = <BankIdNonce<T>>::get() + 1u32.into();
-        while Self::is_bank(bank_nonce_id) {
-            bank_nonce_id += 1u32.into();
+    /// Shares of `who` still locked after their membership proposal
+    pub fn locked_shares_of(
+        bank_id: T::BankId,
+        who: &T::AccountId,
+    ) -> T::Shares {
+        match <LockedShares<T>>::get(bank_id, who) {
+            Some((shares, until))
+                if <frame_system::Module<T>>::block_number() < until =>
+            {
+                shares
+            }
+            _ => Zero::zero(),
         }
-        <BankIdNonce<T>>::put(bank_nonce_id);
-        bank_nonce_id
     }
-    fn generate_spend_uid(seed: T::BankId) -> T::SpendId {
-        let mut id_nonce = <SpendNonceMap<T>>::get(seed) + 1u32.into();
-        while Self::is_spend(seed, id_nonce) {
-            id_nonce += 1u32.into();
+    /// Locks freshly minted shares for the vesting period, extending any live lock
+    fn lock_minted_shares(
+        bank_id: T::BankId,
+        who: &T::AccountId,
+        shares: T::Shares,
+    ) {
+        let period = T::ShareVestingPeriod::get();
+        if period.is_zero() || shares.is_zero() {
+            return
         }
-        <SpendNonceMap<T>>::insert(seed, id_nonce);
-        id_nonce
+        let locked = Self::locked_shares_of(bank_id, who) + shares;
+        let until = <frame_system::Module<T>>::block_number() + period;
+        <LockedShares<T>>::insert(bank_id, who, (locked, until));
+        Self::deposit_event(RawEvent::SharesLocked(
+            bank_id,
+            who.clone(),
+            locked,
+            until,
+        ));
     }
-    fn generate_proposal_uid(seed: T::BankId) -> T::MemId {
-        let mut id_nonce = <ProposalNonceMap<T>>::get(seed) + 1u32.into();
-        while Self::is_proposal(seed, id_nonce) {
-            id_nonce += 1u32.into();
+    /// True if following delegations from `from` leads to `target`, which
+    /// terminates because delegations never form a cycle
+    fn delegation_reaches(
+        bank_id: T::BankId,
+        from: &T::AccountId,
+        target: &T::AccountId,
+    ) -> bool {
+        let mut current = from.clone();
+        loop {
+            if &current == target {
+                return true
+            }
+            match <Delegates<T>>::get(bank_id, &current) {
+                Some(next) => current = next,
+                None => return false,
+            }
         }
-        <ProposalNonceMap<T>>::insert(seed, id_nonce);
-        id_nonce
     }
-    pub fn get_org_bank(org: T::OrgId) -> Result<T::BankId, DispatchError> {
-        let mut ret = <BankStores<T>>::iter()
-            .filter(|(_, bank_state)| bank_state.org() == org)
-            .map(|(bank_id, _)| bank_id)
-            .collect::<Vec<T::BankId>>();
-        if !ret.is_empty() {
-            Ok(ret
-                .pop()
-                .expect("just checked len > 0 to enter if branch; qed"))
-        } else {
-            Err(Error::<T>::NoBanksForOrg.into())
+    /// True if `who` is a member of the bank's org or one of its observers
+    pub fn is_observer_or_member(
+        bank_id: T::BankId,
+        who: &T::AccountId,
+    ) -> bool {
+        <Observers<T>>::get(bank_id, who).is_some()
+            || <BankStores<T>>::get(bank_id).map_or(false, |bank| {
+                <org::Module<T>>::is_member_of_group(bank.org(), who)
+            })
+    }
+    /// Proposer and bond of a live spend proposal, only for members and observers
+    pub fn spend_details(
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        viewer: T::AccountId,
+    ) -> Option<SpendDetail<T::AccountId, BalanceOf<T>>> {
+        if !Self::is_observer_or_member(bank_id, &viewer) {
+            return None
         }
+        let prop = <SpendProps<T>>::get(bank_id, spend_id)?;
+        Some(SpendDetail {
+            proposer: Some(prop.proposer()),
+            bond: <SpendBonds<T>>::get(bank_id, spend_id).map(|(_, bond)| bond),
+        })
+    }
+    /// Page of the bank's spend proposals ordered by id after `start_after`, only those
+    /// in a live vote if `voting_only`, with the cursor for the next page if any remain
+    pub fn spend_proposals_for_bank(
+        bank_id: T::BankId,
+        voting_only: bool,
+        start_after: Option<T::SpendId>,
+        limit: u32,
+    ) -> (Vec<(T::SpendId, SpendProp<T>)>, Option<T::SpendId>) {
+        Self::page(
+            <SpendProps<T>>::iter_prefix(bank_id)
+                .filter(|(_, prop)| !voting_only || prop.state().is_voting())
+                .collect(),
+            start_after,
+            limit,
+        )
+    }
+    /// Page of the spend proposals made by `who` across all banks ordered by bank and
+    /// spend id after `start_after`, with the cursor for the next page if any remain
+    pub fn proposals_by(
+        who: T::AccountId,
+        start_after: Option<(T::BankId, T::SpendId)>,
+        limit: u32,
+    ) -> (
+        Vec<((T::BankId, T::SpendId), SpendProp<T>)>,
+        Option<(T::BankId, T::SpendId)>,
+    ) {
+        Self::page(
+            <SpendProps<T>>::iter()
+                .filter(|(_, _, prop)| prop.proposer() == who)
+                .map(|(bank_id, spend_id, prop)| ((bank_id, spend_id), prop))
+                .collect(),
+            start_after,
+            limit,
+        )
+    }
+    /// Page of the bank's membership proposals ordered by id after `start_after`, only
+    /// those in a live vote if `voting_only`, with the cursor for the next page if any remain
+    pub fn member_proposals_for_bank(
+        bank_id: T::BankId,
+        voting_only: bool,
+        start_after: Option<T::MemId>,
+        limit: u32,
+    ) -> (Vec<(T::MemId, MemberProp<T>)>, Option<T::MemId>) {
+        Self::page(
+            <MemberProps<T>>::iter_prefix(bank_id)
+                .filter(|(_, prop)| !voting_only || prop.state().is_voting())
+                .collect(),
+            start_after,
+            limit,
+        )
+    }
+    /// Sorts by id and returns at most `limit` (capped by `MaxPageSize`) items after
+    /// `start_after`, with the last id returned if more remain
+    fn page<Id: Ord + Copy, Item>(
+        mut items: Vec<(Id, Item)>,
+        start_after: Option<Id>,
+        limit: u32,
+    ) -> (Vec<(Id, Item)>, Option<Id>) {
+        items.sort_by_key(|(id, _)| *id);
+        let limit = limit.min(T::MaxPageSize::get()) as usize;
+        let mut page = items
+            .into_iter()
+            .filter(|(id, _)| {
+                start_after.map(|after| *id > after).unwrap_or(true)
+            })
+            .collect::<Vec<_>>();
+        let next = if page.len() > limit {
+            page.truncate(limit);
+            page.last().map(|(id, _)| *id)
+        } else {
+            None
+        };
+        (page, next)
+    }
+    /// Page of the bank's live votes in which `who` can vote but has not yet
+    pub fn pending_actions_for_member(
+        bank_id: T::BankId,
+        who: T::AccountId,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PendingAction<T::SpendId, T::MemId, T::VoteId>> {
+        let mut spends = <SpendProps<T>>::iter_prefix(bank_id)
+            .filter_map(|(spend_id, prop)| {
+                match prop.state() {
+                    SpendState::Voting(vote_id)
+                        if T::VoteMachine::awaits_vote(vote_id, &who) =>
+                    {
+                        Some((spend_id, vote_id))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        spends.sort_by_key(|(spend_id, _)| *spend_id);
+        let mut members = <MemberProps<T>>::iter_prefix(bank_id)
+            .filter_map(|(prop_id, prop)| {
+                match prop.state() {
+                    ProposalState::Voting(vote_id)
+                        if T::VoteMachine::awaits_vote(vote_id, &who) =>
+                    {
+                        Some((prop_id, vote_id))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        members.sort_by_key(|(prop_id, _)| *prop_id);
+        spends
+            .into_iter()
+            .map(|(s, v)| PendingAction::VoteOnSpend(s, v))
+            .chain(
+                members
+                    .into_iter()
+                    .map(|(m, v)| PendingAction::VoteOnMember(m, v)),
+            )
+            .skip(start as usize)
+            .take(limit as usize)
+            .collect()
     }
 }
 
 // // Helper runtime storage method
 impl<T: Trait> Module<T> {
+    /// Returns the bonus shares minted by the bank's tribute match
     fn execute_member_proposal(
         bank: BankSt<T>,
         applicant: T::AccountId,
         tribute: BalanceOf<T>,
         shares_to_mint: T::Shares,
-    ) -> DispatchResult {
-        // transfer the tribute from the applicant to the bank
-        <T as Trait>::Currency::transfer(
-            &applicant,
-            &Self::bank_account_id(bank.id()),
-            tribute,
-            ExistenceRequirement::KeepAlive,
-        )?;
-        // mint shares in bank.org() for the applicant
-        <org::Module<T>>::issue(
-            bank.org(),
-            applicant,
-            shares_to_mint,
-            false, // not batch issuance
-        )?;
-        Ok(())
+        loot_to_mint: T::Shares,
+    ) -> Result<T::Shares, DispatchError> {
+        let mut bonus =
+            <TributeMatch<T>>::get(bank.id()).mul_floor(shares_to_mint);
+        // checked before the tribute moves so it can be refunded if this fails
+        if let Some(cap) = <MaxTotalShares<T>>::get(bank.id()) {
+            let total = <org::Module<T>>::outstanding_shares(bank.org())
+                .checked_add(&shares_to_mint)
+                .filter(|total| *total <= cap)
+                .ok_or(Error::<T>::MembershipWouldExceedMaxTotalShares)?;
+            // the bonus only dilutes up to the cap
+            bonus = bonus.min(cap - total);
+        }
+        let member_cap = Self::max_shares_per_member_of(bank.id());
+        let would_hold = <org::Module<T>>::members(bank.org(), &applicant)
+            .map(|profile| profile.total())
+            .unwrap_or_else(Zero::zero)
+            .checked_add(&shares_to_mint)
+            .filter(|would_hold| *would_hold <= member_cap)
+            .ok_or(Error::<T>::MembershipWouldExceedMaxSharesPerMember)?;
+        bonus = bonus.min(member_cap - would_hold);
+        let shares_to_mint = shares_to_mint + bonus;
+        // the tribute stays escrowed for the caller to refund if issuance fails
+        with_transaction(|| {
+            // move the tribute escrowed at proposal time into the bank
+            if let Err(e) = <T as Trait>::Currency::repatriate_reserved(
+                &applicant,
+                &Self::bank_account_id(bank.id()),
+                tribute,
+                BalanceStatus::Free,
+            ) {
+                return TransactionOutcome::Rollback(Err(e))
+            }
+            // mint shares in bank.org() for the applicant
+            if !shares_to_mint.is_zero() {
+                if let Err(e) = <org::Module<T>>::issue(
+                    bank.org(),
+                    applicant.clone(),
+                    shares_to_mint,
+                    false, // not batch issuance
+                ) {
+                    return TransactionOutcome::Rollback(Err(e))
+                }
+            }
+            TransactionOutcome::Commit(Ok(()))
+        })?;
+        if !shares_to_mint.is_zero() {
+            Self::lock_minted_shares(bank.id(), &applicant, shares_to_mint);
+        }
+        <MemberStats<T>>::mutate(bank.id(), &applicant, |s| {
+            s.tribute_paid = s.tribute_paid.saturating_add(tribute)
+        });
+        // loot is tracked by the bank because it carries no vote in the org
+        if !loot_to_mint.is_zero() {
+            <Loot<T>>::mutate(bank.id(), &applicant, |l| *l += loot_to_mint);
+            <TotalLoot<T>>::mutate(bank.id(), |l| *l += loot_to_mint);
+        }
+        Ok(bonus)
+    }
+    fn _propose_guild_kick(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        target: T::AccountId,
+    ) -> Result<T::MemId, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::BankMustExistToProposeFrom)?;
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), &caller),
+            Error::<T>::MustBeMemberToSponsorProposal
+        );
+        ensure!(
+            <org::Module<T>>::is_member_of_group(bank.org(), &target),
+            Error::<T>::CannotKickAccountThatIsNotMember
+        );
+        Self::ensure_below_open_proposal_cap(bank_id, caller)?;
+        Self::charge_proposal_fee(caller, bank_id)?;
+        let id = Self::generate_proposal_uid(bank_id);
+        <KickProps<T>>::insert(
+            bank_id,
+            id,
+            KickProp::<T>::new(bank_id, id, target),
+        );
+        <ActiveKickProps<T>>::mutate(bank_id, |ids| ids.push(id));
+        Self::track_open_proposal(bank_id, id, caller);
+        <KickExpiry<T>>::insert(bank_id, id, Self::proposal_expiry());
+        Ok(id)
+    }
+    fn _trigger_vote_on_kick_proposal(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        kick_id: T::MemId,
+    ) -> Result<T::VoteId, DispatchError> {
+        let bank = <BankStores<T>>::get(bank_id)
+            .ok_or(Error::<T>::CannotTriggerVoteIfBaseBankDNE)?;
+        ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
+        Self::ensure_can_trigger_vote(&bank, caller)?;
+        let kick_proposal = <KickProps<T>>::get(bank_id, kick_id)
+            .ok_or(Error::<T>::CannotTriggerVoteIfProposalDNE)?;
+        match kick_proposal.state() {
+            ProposalState::WaitingForApproval
+            | ProposalState::FailedQuorum(_) => {
+                // kicks change membership so use the membership threshold
+                let threshold_id = Self::member_threshold_id(&bank);
+                let new_vote_id =
+                    T::VoteMachine::invoke_threshold(threshold_id, None, None)?;
+                <KickProps<T>>::insert(
+                    bank_id,
+                    kick_id,
+                    kick_proposal.set_state(ProposalState::Voting(new_vote_id)),
+                );
+                <VoteStarted<T>>::insert(
+                    new_vote_id,
+                    <frame_system::Module<T>>::block_number(),
+                );
+                Ok(new_vote_id)
+            }
+            _ => {
+                Err(Error::<T>::CannotTriggerVoteFromCurrentKickProposalState
+                    .into())
+            }
+        }
+    }
+    /// Burns all of the target's voting shares and credits them as loot so
+    /// they may only claim their portion of the bank, returning the amount
+    fn execute_guild_kick(
+        bank: BankSt<T>,
+        target: &T::AccountId,
+    ) -> Result<T::Shares, DispatchError> {
+        let shares = <org::Module<T>>::members(bank.org(), target)
+            .map(|profile| profile.total())
+            .unwrap_or_else(Zero::zero);
+        ensure!(
+            !shares.is_zero(),
+            Error::<T>::CannotKickAccountThatIsNotMember
+        );
+        <org::Module<T>>::burn(bank.org(), target.clone(), None, false)?;
+        <Loot<T>>::mutate(bank.id(), target, |l| *l += shares);
+        <TotalLoot<T>>::mutate(bank.id(), |l| *l += shares);
+        Ok(shares)
+    }
+    fn poll_kick_proposal(
+        prop: KickProp<T>,
+    ) -> Result<ProposalState<T::VoteId>, DispatchError> {
+        let bank = <BankStores<T>>::get(prop.bank_id())
+            .ok_or(Error::<T>::CannotPollProposalIfBaseBankDNE)?;
+        // stored state is authoritative so a kick is never executed twice
+        let prop = <KickProps<T>>::get(prop.bank_id(), prop.prop_id())
+            .ok_or(Error::<T>::CannotPollProposalIfProposalDNE)?;
+        let vote_id = match prop.state() {
+            ProposalState::Voting(vote_id) => vote_id,
+            // proposals in a live vote never expire so the vote is not cut short
+            ProposalState::WaitingForApproval
+            | ProposalState::FailedQuorum(_)
+                if Self::proposal_expired(<KickExpiry<T>>::get(
+                    prop.bank_id(),
+                    prop.prop_id(),
+                )) =>
+            {
+                Self::expire_kick_proposal(&prop);
+                return Ok(ProposalState::Expired)
+            }
+            _ => return Ok(prop.state()),
+        };
+        let vote_outcome = T::VoteMachine::get_vote_outcome(vote_id)?;
+        let new_state = if vote_outcome == VoteOutcome::Approved {
+            Self::close_vote(prop.bank_id(), vote_id);
+            if !Self::vote_met_quorum(prop.bank_id(), vote_id)? {
+                Self::deposit_event(RawEvent::ProposalFailedQuorum(
+                    prop.bank_id(),
+                    vote_id,
+                ));
+                ProposalState::FailedQuorum(vote_id)
+            } else if let Ok(shares) =
+                Self::execute_guild_kick(bank, &prop.target())
+            {
+                Self::deposit_event(RawEvent::GuildKickExecuted(
+                    prop.bank_id(),
+                    prop.prop_id(),
+                    prop.target(),
+                    shares,
+                ));
+                ProposalState::ApprovedAndExecuted
+            } else {
+                // target already left the org so there is nothing to convert
+                ProposalState::ApprovedButNotExecuted
+            }
+        } else if vote_outcome == VoteOutcome::Rejected {
+            Self::close_vote(prop.bank_id(), vote_id);
+            ProposalState::Rejected(vote_id)
+        } else if Self::vote_expired(prop.bank_id(), vote_id) {
+            Self::close_vote(prop.bank_id(), vote_id);
+            Self::deposit_event(RawEvent::VoteForceResolved(
+                prop.bank_id(),
+                vote_id,
+            ));
+            ProposalState::Rejected(vote_id)
+        } else {
+            return Ok(prop.state())
+        };
+        <KickProps<T>>::insert(
+            prop.bank_id(),
+            prop.prop_id(),
+            prop.set_state(new_state),
+        );
+        Ok(new_state)
     }
 }
 
@@ -508,7 +4542,7 @@ impl<T: Trait>
             Error::<T>::ThresholdCannotBeSetForOrg
         );
         // register input threshold
-        let threshold_id = <vote::Module<T>>::register_threshold(threshold)?;
+        let threshold_id = T::VoteMachine::register_threshold(threshold)?;
         // generate new moloch bank identifier
         let id = Self::generate_bank_uid();
         // perform fallible transfer
@@ -524,6 +4558,11 @@ impl<T: Trait>
         <BankStores<T>>::insert(id, new_bank);
         // iterate total bank count
         <TotalBankCount>::mutate(|count| *count += 1u32);
+        // schedule the first polls of the bank's proposals
+        let now = <frame_system::Module<T>>::block_number();
+        Self::schedule_spend_poll(id, now);
+        Self::schedule_member_poll(id, now);
+        Self::log_activity(id, BankActivity::Opened);
         // return new moloch bank identifier
         Ok(id)
     }
@@ -542,18 +4581,121 @@ impl<T: Trait>
         amount: BalanceOf<T>,
         dest: T::AccountId,
     ) -> Result<Self::SpendId, DispatchError> {
+        ensure!(amount >= T::MinSpend::get(), Error::<T>::SpendBelowMinimum);
         let bank = <BankStores<T>>::get(bank_id)
             .ok_or(Error::<T>::BankMustExistToProposeFrom)?;
+        ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
         ensure!(
             <org::Module<T>>::is_member_of_group(bank.org(), &caller),
             Error::<T>::MustBeMemberToSponsorProposal
         );
+        ensure!(
+            Self::is_allowlisted_dest(bank_id, &dest),
+            Error::<T>::SpendDestinationNotAllowlisted
+        );
+        // the minimum deposit stays in the bank to keep it open
+        ensure!(
+            amount
+                <= Self::bank_balance(bank_id)
+                    .saturating_sub(T::MinDeposit::get()),
+            Error::<T>::SpendExceedsBankBalance
+        );
+        Self::ensure_reproposal_allowed(
+            bank_id,
+            Self::spend_key(amount, &dest),
+        )?;
+        Self::ensure_below_open_proposal_cap(bank_id, caller)?;
+        let bond = T::SpendBond::get();
+        if !bond.is_zero() {
+            <T as Trait>::Currency::reserve(caller, bond).map_err(|_| {
+                Error::<T>::InsufficientBalanceToReserveSpendBond
+            })?;
+        }
+        if let Err(e) = Self::charge_proposal_fee(caller, bank_id) {
+            <T as Trait>::Currency::unreserve(caller, bond);
+            return Err(e)
+        }
         let new_spend_id = Self::generate_spend_uid(bank_id);
-        let spend_proposal =
-            SpendProp::<T>::new(bank_id, new_spend_id, amount, dest);
+        if !bond.is_zero() {
+            <SpendBonds<T>>::insert(
+                bank_id,
+                new_spend_id,
+                (caller.clone(), bond),
+            );
+        }
+        let spend_proposal = SpendProp::<T>::new(
+            bank_id,
+            new_spend_id,
+            caller.clone(),
+            amount,
+            dest,
+        );
         <SpendProps<T>>::insert(bank_id, new_spend_id, spend_proposal);
+        <ActiveSpendProps<T>>::mutate(bank_id, |ids| ids.push(new_spend_id));
+        <SpendOpenedBy<T>>::insert(bank_id, new_spend_id, caller);
+        <OpenProposalCount<T>>::mutate(bank_id, caller, |count| *count += 1);
+        Self::log_activity(bank_id, BankActivity::SpendProposed(new_spend_id));
+        <SpendExpiry<T>>::insert(
+            bank_id,
+            new_spend_id,
+            Self::proposal_expiry(),
+        );
+        <MemberStats<T>>::mutate(bank_id, caller, |s| {
+            s.spends_proposed = s.spends_proposed.saturating_add(1)
+        });
         Ok(new_spend_id)
     }
+    fn _amend_spend_proposal(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        spend_id: T::SpendId,
+        new_amount: Option<BalanceOf<T>>,
+        new_dest: Option<T::AccountId>,
+    ) -> DispatchResult {
+        let prop = <SpendProps<T>>::get(bank_id, spend_id)
+            .ok_or(Error::<T>::CannotAmendSpendProposalThatDNE)?;
+        ensure!(
+            prop.proposer() == *caller,
+            Error::<T>::NotPermittedToAmendSpendProposal
+        );
+        // voters must decide on the proposal as it was when the vote started
+        ensure!(
+            prop.state() == SpendState::WaitingForApproval,
+            Error::<T>::CannotAmendSpendProposalOnceVoteTriggered
+        );
+        ensure!(
+            new_amount.is_some() || new_dest.is_some(),
+            Error::<T>::SpendAmendmentMustChangeAmountOrDest
+        );
+        // split, dividend, inter-bank and recurring spends pay out from their own
+        // records so the proposal's amount and destination are not the payout
+        ensure!(
+            !<SplitPayouts<T>>::contains_key(bank_id, spend_id)
+                && !<DividendSpends<T>>::get(bank_id, spend_id)
+                && !<InterBankSpends<T>>::contains_key(bank_id, spend_id)
+                && !<RecurringSpends<T>>::contains_key(bank_id, spend_id),
+            Error::<T>::CannotAmendSpendWithCustomPayout
+        );
+        let amount = new_amount.unwrap_or_else(|| prop.amount());
+        let dest = new_dest.unwrap_or_else(|| prop.dest());
+        ensure!(amount >= T::MinSpend::get(), Error::<T>::SpendBelowMinimum);
+        ensure!(
+            Self::is_allowlisted_dest(bank_id, &dest),
+            Error::<T>::SpendDestinationNotAllowlisted
+        );
+        ensure!(
+            amount
+                <= Self::bank_balance(bank_id)
+                    .saturating_sub(T::MinDeposit::get()),
+            Error::<T>::SpendExceedsBankBalance
+        );
+        Self::ensure_reproposal_allowed(
+            bank_id,
+            Self::spend_key(amount, &dest),
+        )?;
+        <SpendProps<T>>::insert(bank_id, spend_id, prop.amend(amount, dest));
+        Ok(())
+    }
     fn _trigger_vote_on_spend_proposal(
         caller: &T::AccountId,
         bank_id: T::BankId,
@@ -561,23 +4703,60 @@ impl<T: Trait>
     ) -> Result<Self::VoteId, DispatchError> {
         let bank = <BankStores<T>>::get(bank_id)
             .ok_or(Error::<T>::CannotTriggerVoteIfBaseBankDNE)?;
+        ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
         let spend_proposal = <SpendProps<T>>::get(bank_id, spend_id)
             .ok_or(Error::<T>::CannotTriggerVoteIfProposalDNE)?;
-        ensure!(
-            <org::Module<T>>::is_member_of_group(bank.org(), caller),
-            Error::<T>::NotPermittedToTriggerVoteForBankAccount
-        );
+        Self::ensure_can_trigger_vote(&bank, caller)?;
         match spend_proposal.state() {
-            SpendState::WaitingForApproval => {
-                // dispatch vote with bank's default threshold
-                let new_vote_id = <vote::Module<T>>::invoke_threshold(
-                    bank.threshold_id(),
-                    None, // TODO: use vote info ref here instead of None
-                    None,
-                )?;
+            SpendState::WaitingForApproval | SpendState::FailedQuorum(_) => {
+                // the reservation and the vote are rolled back together on failure
+                let new_vote_id = with_transaction(|| {
+                    // earmark the funds so concurrent votes cannot over-commit the bank
+                    if <T as Trait>::Currency::reserve(
+                        &Self::bank_account_id(bank_id),
+                        spend_proposal.amount(),
+                    )
+                    .is_err()
+                    {
+                        return TransactionOutcome::Rollback(Err(
+                            Error::<T>::BankFundsAlreadyReservedByLiveSpends
+                                .into(),
+                        ))
+                    }
+                    // dispatch vote with the bank's spend threshold if set
+                    let threshold_id = <SpendThreshold<T>>::get(bank_id)
+                        .unwrap_or_else(|| bank.threshold_id());
+                    let vote_id = match T::VoteMachine::invoke_threshold(
+                        threshold_id,
+                        None, // TODO: use vote info ref here instead of None
+                        None,
+                    ) {
+                        Ok(id) => id,
+                        Err(e) => return TransactionOutcome::Rollback(Err(e)),
+                    };
+                    if <ConvictionEnabled<T>>::get(bank_id) {
+                        if let Err(e) = T::VoteMachine::enable_conviction(
+                            vote_id,
+                            bank.org(),
+                            T::ConvictionLockPeriod::get(),
+                        ) {
+                            return TransactionOutcome::Rollback(Err(e))
+                        }
+                    }
+                    TransactionOutcome::Commit(Ok(vote_id))
+                })?;
+                <SpendReservations<T>>::insert(
+                    bank_id,
+                    spend_id,
+                    spend_proposal.amount(),
+                );
                 let new_spend_proposal =
                     spend_proposal.set_state(SpendState::Voting(new_vote_id));
                 <SpendProps<T>>::insert(bank_id, spend_id, new_spend_proposal);
+                <VoteStarted<T>>::insert(
+                    new_vote_id,
+                    <frame_system::Module<T>>::block_number(),
+                );
                 Ok(new_vote_id)
             }
             _ => {
@@ -597,24 +4776,30 @@ impl<T: Trait>
             bank.is_controller(caller),
             Error::<T>::NotPermittedToSudoApproveForBankAccount
         );
+        ensure!(!<FrozenBanks<T>>::get(bank_id), Error::<T>::BankFrozen);
         let spend_proposal = <SpendProps<T>>::get(bank_id, spend_id).ok_or(
             Error::<T>::CannotSudoApproveSpendProposalIfSpendProposalDNE,
         )?;
         match spend_proposal.state() {
             SpendState::WaitingForApproval | SpendState::Voting(_) => {
-                // TODO: if Voting, remove the current live vote
-                let new_spend_proposal = if let Ok(()) =
-                    <T as Trait>::Currency::transfer(
-                        &Self::bank_account_id(bank_id),
-                        &spend_proposal.dest(),
-                        spend_proposal.amount(),
-                        ExistenceRequirement::KeepAlive,
-                    ) {
-                    spend_proposal.set_state(SpendState::ApprovedAndExecuted)
-                } else {
-                    spend_proposal.set_state(SpendState::ApprovedButNotExecuted)
-                };
+                if let SpendState::Voting(vote_id) = spend_proposal.state() {
+                    Self::close_vote(bank_id, vote_id);
+                    T::VoteMachine::close_vote(vote_id)?;
+                    Self::deposit_event(RawEvent::LiveVoteCancelledBySudo(
+                        bank_id, spend_id, vote_id,
+                    ));
+                }
+                let new_spend_proposal = Self::execute_spend(spend_proposal);
+                Self::deposit_event(RawEvent::SpendProposalPolled(
+                    bank_id,
+                    spend_id,
+                    new_spend_proposal.state(),
+                    None,
+                    ResolutionTrigger::Sudo(caller.clone()),
+                ));
                 <SpendProps<T>>::insert(bank_id, spend_id, new_spend_proposal);
+                Self::return_spend_bond(bank_id, spend_id);
+                Self::credit_passed_spend(bank_id, spend_id);
                 Ok(())
             }
             _ => {
@@ -628,36 +4813,168 @@ impl<T: Trait>
     ) -> Result<Self::SpendState, DispatchError> {
         let _ = <BankStores<T>>::get(prop.bank_id())
             .ok_or(Error::<T>::CannotPollProposalIfBaseBankDNE)?;
-        let _ = <SpendProps<T>>::get(prop.bank_id(), prop.spend_id())
+        // stored state is authoritative so a spend approved since `prop` was
+        // read (e.g. by sudo) is never executed twice
+        let prop = <SpendProps<T>>::get(prop.bank_id(), prop.spend_id())
             .ok_or(Error::<T>::CannotPollProposalIfProposalDNE)?;
         match prop.state() {
             SpendState::Voting(vote_id) => {
-                let vote_outcome =
-                    <vote::Module<T>>::get_vote_outcome(vote_id)?;
+                let vote_outcome = T::VoteMachine::get_vote_outcome(vote_id)?;
                 if vote_outcome == VoteOutcome::Approved {
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    if !Self::vote_met_quorum(prop.bank_id(), vote_id)? {
+                        Self::release_spend_funds(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                        );
+                        let new_spend_proposal =
+                            prop.set_state(SpendState::FailedQuorum(vote_id));
+                        <SpendProps<T>>::insert(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                            new_spend_proposal,
+                        );
+                        Self::deposit_event(RawEvent::ProposalFailedQuorum(
+                            prop.bank_id(),
+                            vote_id,
+                        ));
+                        return Ok(SpendState::FailedQuorum(vote_id))
+                    }
+                    let window = T::GraceWindow::get();
+                    if !window.is_zero() {
+                        // funds stay reserved until the window passes
+                        let ends =
+                            <frame_system::Module<T>>::block_number() + window;
+                        <SpendGraceEnds<T>>::insert(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                            ends,
+                        );
+                        <SpendProps<T>>::insert(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                            prop.set_state(SpendState::GracePeriod(vote_id)),
+                        );
+                        Self::deposit_event(RawEvent::SpendEnteredGracePeriod(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                            ends,
+                        ));
+                        Self::return_spend_bond(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                        );
+                        Self::credit_passed_spend(
+                            prop.bank_id(),
+                            prop.spend_id(),
+                        );
+                        return Ok(SpendState::GracePeriod(vote_id))
+                    }
                     // approved so try to execute and if not, still approve
-                    let new_spend_proposal = if let Ok(()) =
-                        <T as Trait>::Currency::transfer(
-                            &Self::bank_account_id(prop.bank_id()),
-                            &prop.dest(),
-                            prop.amount(),
-                            ExistenceRequirement::KeepAlive,
-                        ) {
-                        prop.set_state(SpendState::ApprovedAndExecuted)
-                    } else {
-                        prop.set_state(SpendState::ApprovedButNotExecuted)
-                    };
+                    let new_spend_proposal = Self::execute_spend(prop.clone());
                     let ret_state = new_spend_proposal.state();
                     <SpendProps<T>>::insert(
                         prop.bank_id(),
                         prop.spend_id(),
                         new_spend_proposal,
                     );
+                    Self::return_spend_bond(prop.bank_id(), prop.spend_id());
+                    Self::credit_passed_spend(prop.bank_id(), prop.spend_id());
                     Ok(ret_state)
+                } else if vote_outcome == VoteOutcome::Rejected {
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    let new_spend_proposal =
+                        prop.set_state(SpendState::Rejected(vote_id));
+                    <SpendProps<T>>::insert(
+                        prop.bank_id(),
+                        prop.spend_id(),
+                        new_spend_proposal,
+                    );
+                    Self::slash_spend_bond(prop.bank_id(), prop.spend_id());
+                    Self::clear_spend_side_tables(
+                        prop.bank_id(),
+                        prop.spend_id(),
+                    );
+                    Self::record_rejection(
+                        prop.bank_id(),
+                        Self::spend_key(prop.amount(), &prop.dest()),
+                    );
+                    Ok(SpendState::Rejected(vote_id))
+                } else if Self::vote_expired(prop.bank_id(), vote_id) {
+                    // current tallies have not met the threshold so reject,
+                    // returning the bond because no one voted it down
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    let new_spend_proposal =
+                        prop.set_state(SpendState::Rejected(vote_id));
+                    <SpendProps<T>>::insert(
+                        prop.bank_id(),
+                        prop.spend_id(),
+                        new_spend_proposal,
+                    );
+                    Self::return_spend_bond(prop.bank_id(), prop.spend_id());
+                    Self::clear_spend_side_tables(
+                        prop.bank_id(),
+                        prop.spend_id(),
+                    );
+                    Self::deposit_event(RawEvent::VoteForceResolved(
+                        prop.bank_id(),
+                        vote_id,
+                    ));
+                    Ok(SpendState::Rejected(vote_id))
                 } else {
                     Ok(prop.state())
                 }
             }
+            SpendState::GracePeriod(_)
+                if <SpendGraceEnds<T>>::get(
+                    prop.bank_id(),
+                    prop.spend_id(),
+                )
+                .map_or(true, |ends| {
+                    <frame_system::Module<T>>::block_number() >= ends
+                }) =>
+            {
+                // no veto during the window so the spend pays out
+                <SpendGraceEnds<T>>::remove(prop.bank_id(), prop.spend_id());
+                let new_spend_proposal = Self::execute_spend(prop);
+                let ret_state = new_spend_proposal.state();
+                <SpendProps<T>>::insert(
+                    new_spend_proposal.bank_id(),
+                    new_spend_proposal.spend_id(),
+                    new_spend_proposal,
+                );
+                Ok(ret_state)
+            }
+            SpendState::ApprovedButNotExecuted
+                if <SpendConditions<T>>::contains_key(
+                    prop.bank_id(),
+                    prop.spend_id(),
+                ) || <CapDeferredSpends<T>>::get(
+                    prop.bank_id(),
+                    prop.spend_id(),
+                ) =>
+            {
+                // retry approved conditional spends until the condition holds
+                // and capped spends until a window has room
+                let new_spend_proposal = Self::execute_spend(prop);
+                let ret_state = new_spend_proposal.state();
+                <SpendProps<T>>::insert(
+                    new_spend_proposal.bank_id(),
+                    new_spend_proposal.spend_id(),
+                    new_spend_proposal,
+                );
+                Ok(ret_state)
+            }
+            // proposals in a live vote never expire so the vote is not cut short
+            SpendState::WaitingForApproval | SpendState::FailedQuorum(_)
+                if Self::proposal_expired(<SpendExpiry<T>>::get(
+                    prop.bank_id(),
+                    prop.spend_id(),
+                )) =>
+            {
+                Self::expire_spend_proposal(&prop);
+                Ok(SpendState::Expired)
+            }
             _ => Ok(prop.state()),
         }
     }
@@ -680,6 +4997,7 @@ impl<T: Trait>
         bank_id: T::BankId,
         tribute: BalanceOf<T>,
         shares_requested: T::Shares,
+        loot_requested: T::Shares,
         applicant: T::AccountId,
     ) -> Result<Self::MemberPropId, DispatchError> {
         let bank = <BankStores<T>>::get(bank_id)
@@ -688,16 +5006,30 @@ impl<T: Trait>
             <org::Module<T>>::is_member_of_group(bank.org(), &caller),
             Error::<T>::MustBeMemberToSponsorProposal
         );
-        let id = Self::generate_proposal_uid(bank_id);
-        let member_proposal = MemberProp::<T>::new(
+        // others' funds are only escrowed by their own membership request
+        ensure!(
+            tribute.is_zero() || caller == &applicant,
+            Error::<T>::TributeMustBeEscrowedByApplicant
+        );
+        Self::ensure_reproposal_allowed(
+            bank_id,
+            Self::member_key(&applicant, shares_requested),
+        )?;
+        // tribute is escrowed until the proposal is resolved
+        <T as Trait>::Currency::reserve(&applicant, tribute)
+            .map_err(|_| Error::<T>::InsufficientBalanceToReserveTribute)?;
+        Self::open_member_proposal(
+            caller,
             bank_id,
-            id,
             tribute,
             shares_requested,
-            applicant,
-        );
-        <MemberProps<T>>::insert(bank_id, id, member_proposal);
-        Ok(id)
+            loot_requested,
+            applicant.clone(),
+        )
+        .map_err(|e| {
+            <T as Trait>::Currency::unreserve(&applicant, tribute);
+            e
+        })
     }
     fn _trigger_vote_on_member_proposal(
         caller: &T::AccountId,
@@ -706,17 +5038,19 @@ impl<T: Trait>
     ) -> Result<Self::VoteId, DispatchError> {
         let bank = <BankStores<T>>::get(bank_id)
             .ok_or(Error::<T>::CannotTriggerVoteIfBaseBankDNE)?;
-        ensure!(
-            <org::Module<T>>::is_member_of_group(bank.org(), &caller),
-            Error::<T>::NotPermittedToTriggerVoteForBankAccount
-        );
+        Self::ensure_can_trigger_vote(&bank, caller)?;
         let member_proposal = <MemberProps<T>>::get(bank_id, proposal_id)
             .ok_or(Error::<T>::CannotTriggerVoteIfProposalDNE)?;
         match member_proposal.state() {
-            ProposalState::WaitingForApproval => {
-                // dispatch vote with bank's default threshold
-                let new_vote_id = <vote::Module<T>>::invoke_threshold(
-                    bank.threshold_id(),
+            ProposalState::WaitingForApproval
+            | ProposalState::FailedQuorum(_) => {
+                // dispatch vote with the proposal's own threshold, else the
+                // bank's membership threshold if set
+                let threshold_id =
+                    <MemberThresholdOverrides<T>>::get(bank_id, proposal_id)
+                        .unwrap_or_else(|| Self::member_threshold_id(&bank));
+                let new_vote_id = T::VoteMachine::invoke_threshold(
+                    threshold_id,
                     None, // TODO: use vote info ref here instead of None
                     None,
                 )?;
@@ -727,6 +5061,10 @@ impl<T: Trait>
                     proposal_id,
                     new_member_proposal,
                 );
+                <VoteStarted<T>>::insert(
+                    new_vote_id,
+                    <frame_system::Module<T>>::block_number(),
+                );
                 Ok(new_vote_id)
             }
             _ => {
@@ -740,36 +5078,119 @@ impl<T: Trait>
     ) -> Result<Self::PropState, DispatchError> {
         let bank = <BankStores<T>>::get(prop.bank_id())
             .ok_or(Error::<T>::CannotPollProposalIfBaseBankDNE)?;
-        let _ = <MemberProps<T>>::get(prop.bank_id(), prop.prop_id())
+        // stored state is authoritative so a proposal is never executed twice
+        let prop = <MemberProps<T>>::get(prop.bank_id(), prop.prop_id())
             .ok_or(Error::<T>::CannotPollProposalIfProposalDNE)?;
         match prop.state() {
             ProposalState::Voting(vote_id) => {
-                let vote_outcome =
-                    <vote::Module<T>>::get_vote_outcome(vote_id)?;
+                let vote_outcome = T::VoteMachine::get_vote_outcome(vote_id)?;
                 if vote_outcome == VoteOutcome::Approved {
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    if !Self::vote_met_quorum(prop.bank_id(), vote_id)? {
+                        let new_member_proposal = prop
+                            .set_state(ProposalState::FailedQuorum(vote_id));
+                        <MemberProps<T>>::insert(
+                            prop.bank_id(),
+                            prop.prop_id(),
+                            new_member_proposal,
+                        );
+                        Self::deposit_event(RawEvent::ProposalFailedQuorum(
+                            prop.bank_id(),
+                            vote_id,
+                        ));
+                        return Ok(ProposalState::FailedQuorum(vote_id))
+                    }
                     // approved so try to execute and if not, still approve
-                    let new_member_proposal = if let Ok(()) =
-                        Self::execute_member_proposal(
+                    let new_member_proposal =
+                        match Self::execute_member_proposal(
                             bank,
                             prop.applicant(),
                             prop.tribute(),
                             prop.shares_requested(),
+                            prop.loot_requested(),
                         ) {
-                        prop.set_state(ProposalState::ApprovedAndExecuted)
-                    } else {
-                        prop.set_state(ProposalState::ApprovedButNotExecuted)
-                    };
+                            Ok(bonus) => {
+                                if !bonus.is_zero() {
+                                    Self::deposit_event(
+                                        RawEvent::TributeMatched(
+                                            prop.bank_id(),
+                                            prop.prop_id(),
+                                            prop.applicant(),
+                                            bonus,
+                                        ),
+                                    );
+                                }
+                                prop.set_state(
+                                    ProposalState::ApprovedAndExecuted,
+                                )
+                            }
+                            Err(_) => {
+                                Self::refund_tribute(&prop);
+                                prop.set_state(
+                                    ProposalState::ApprovedButNotExecuted,
+                                )
+                            }
+                        };
                     let ret_state = new_member_proposal.state();
                     <MemberProps<T>>::insert(
                         prop.bank_id(),
                         prop.prop_id(),
                         new_member_proposal,
                     );
+                    Self::return_sponsor_bond(&prop);
                     Ok(ret_state)
+                } else if vote_outcome == VoteOutcome::Rejected {
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    let new_member_proposal =
+                        prop.set_state(ProposalState::Rejected(vote_id));
+                    <MemberProps<T>>::insert(
+                        prop.bank_id(),
+                        prop.prop_id(),
+                        new_member_proposal,
+                    );
+                    Self::slash_sponsor_bond(&prop);
+                    Self::refund_tribute(&prop);
+                    Self::record_rejection(
+                        prop.bank_id(),
+                        Self::member_key(
+                            &prop.applicant(),
+                            prop.shares_requested(),
+                        ),
+                    );
+                    Ok(ProposalState::Rejected(vote_id))
+                } else if Self::vote_expired(prop.bank_id(), vote_id) {
+                    // current tallies have not met the threshold so reject,
+                    // returning the sponsor bond because no one voted it down
+                    Self::close_vote(prop.bank_id(), vote_id);
+                    let new_member_proposal =
+                        prop.set_state(ProposalState::Rejected(vote_id));
+                    <MemberProps<T>>::insert(
+                        prop.bank_id(),
+                        prop.prop_id(),
+                        new_member_proposal,
+                    );
+                    Self::return_sponsor_bond(&prop);
+                    Self::refund_tribute(&prop);
+                    Self::deposit_event(RawEvent::VoteForceResolved(
+                        prop.bank_id(),
+                        vote_id,
+                    ));
+                    Ok(ProposalState::Rejected(vote_id))
                 } else {
                     Ok(prop.state())
                 }
             }
+            // proposals in a live vote never expire so the vote is not cut short
+            ProposalState::WaitingForApproval
+            | ProposalState::FailedQuorum(_)
+                if Self::proposal_expired(<MemberExpiry<T>>::get(
+                    prop.bank_id(),
+                    prop.prop_id(),
+                )) =>
+            {
+                Self::expire_member_proposal(&prop);
+                Ok(ProposalState::Expired)
+            }
             _ => Ok(prop.state()),
         }
     }
@@ -777,28 +5198,97 @@ impl<T: Trait>
         caller: T::AccountId,
         bank_id: T::BankId,
     ) -> DispatchResult {
+        Self::ragequit(&caller, bank_id, None)?;
+        Ok(())
+    }
+}
+
+impl<T: Trait> Ragequit<T::AccountId, T::BankId, T::Shares, BalanceOf<T>>
+    for Module<T>
+{
+    fn ragequit(
+        caller: &T::AccountId,
+        bank_id: T::BankId,
+        shares_to_burn: Option<T::Shares>,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
         let bank = <BankStores<T>>::get(bank_id)
             .ok_or(Error::<T>::CannotBurnSharesIfBaseBankDNE)?;
-        let shares_burned =
-            <org::Module<T>>::burn(bank.org(), caller.clone(), None, false)?;
+        let held = <org::Module<T>>::members(bank.org(), caller)
+            .map(|profile| profile.total())
+            .unwrap_or_else(Zero::zero);
+        ensure!(
+            !held.is_zero()
+                && shares_to_burn
+                    .map(|s| !s.is_zero() && s <= held)
+                    .unwrap_or(true),
+            Error::<T>::InsufficientSharesToRagequit
+        );
+        ensure!(
+            shares_to_burn.unwrap_or(held)
+                <= held.saturating_sub(Self::locked_shares_of(bank_id, caller)),
+            Error::<T>::SharesLockedUntilVested
+        );
+        ensure!(
+            T::VoteMachine::conviction_locked_until(bank.org(), caller)
+                .map_or(true, |until| {
+                    until <= <frame_system::Module<T>>::block_number()
+                }),
+            Error::<T>::SharesLockedByConvictionVote
+        );
+        ensure!(
+            !Self::has_vote_in_flight(bank_id, caller),
+            Error::<T>::CannotRagequitWithVoteInFlight
+        );
+        // economic base is voting shares plus loot, read before the burn
+        let economic_base = <org::Module<T>>::outstanding_shares(bank.org())
+            + <TotalLoot<T>>::get(bank_id);
+        let bank_account_id = Self::bank_account_id(bank_id);
+        // funds reserved for live spend votes are not claimable
+        let balance_in_bank = Self::bank_free_balance(bank_id);
+        // shares are only burned if the claim is paid out
+        let (shares_burned, amt_due, fee) = with_transaction(|| {
+            let shares_burned = match <org::Module<T>>::burn(
+                bank.org(),
+                caller.clone(),
+                shares_to_burn,
+                false,
+            ) {
+                Ok(shares_burned) => shares_burned,
+                Err(e) => return TransactionOutcome::Rollback(Err(e)),
+            };
+            let claim = Self::economic_portion(
+                balance_in_bank,
+                shares_burned.total(),
+                economic_base,
+            );
+            // the fee is simply not transferred so it stays in the bank
+            let fee = <RagequitFee<T>>::get(bank_id).mul_floor(claim);
+            let amt_due = claim - fee;
+            if let Err(e) = <T as Trait>::Currency::transfer(
+                &bank_account_id,
+                caller,
+                amt_due,
+                ExistenceRequirement::KeepAlive,
+            ) {
+                return TransactionOutcome::Rollback(Err(e))
+            }
+            TransactionOutcome::Commit(Ok((shares_burned, amt_due, fee)))
+        })?;
         Self::deposit_event(RawEvent::SharesBurned(
             bank.org(),
             shares_burned.total(),
         ));
-        let bank_account_id = Self::bank_account_id(bank_id);
-        let balance_in_bank =
-            <T as Trait>::Currency::total_balance(&bank_account_id);
-        let amt_due = shares_burned.portion().mul_floor(balance_in_bank);
-        <T as Trait>::Currency::transfer(
-            &bank_account_id,
-            &caller,
-            amt_due,
-            ExistenceRequirement::KeepAlive,
-        )?;
         let amt_left = <T as Trait>::Currency::total_balance(&bank_account_id);
         Self::deposit_event(RawEvent::WithdrawnPortion(
             bank_id, amt_due, amt_left,
         ));
-        Ok(())
+        Self::deposit_event(RawEvent::MemberRagequit(
+            caller.clone(),
+            bank_id,
+            shares_burned.total(),
+            amt_due,
+            fee,
+        ));
+        Ok((amt_due, fee))
     }
 }
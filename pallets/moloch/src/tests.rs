@@ -5,7 +5,13 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
-    traits::OnFinalize,
+    traits::{
+        LockableCurrency,
+        OnFinalize,
+        OnInitialize,
+        OnUnbalanced,
+        WithdrawReasons,
+    },
     weights::Weight,
 };
 use sp_core::H256;
@@ -24,9 +30,11 @@ use util::{
     traits::{
         GroupMembership,
         RegisterOrganization,
+        RemoveOrganization,
         ShareInformation,
     },
     vote::{
+        Conviction,
         Threshold,
         ThresholdInput,
         VoterView,
@@ -92,8 +100,21 @@ impl frame_system::Trait for Test {
     type BaseCallFilter = ();
     type SystemWeightInfo = ();
 }
+thread_local! {
+    static EXISTENTIAL_DEPOSIT: std::cell::RefCell<u64> = std::cell::RefCell::new(1);
+}
+pub struct ExistentialDeposit;
+impl ExistentialDeposit {
+    fn set(amount: u64) {
+        EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = amount);
+    }
+}
+impl Get<u64> for ExistentialDeposit {
+    fn get() -> u64 {
+        EXISTENTIAL_DEPOSIT.with(|v| *v.borrow())
+    }
+}
 parameter_types! {
-    pub const ExistentialDeposit: u64 = 1;
     pub const MaxLocks: u32 = 50;
 }
 impl pallet_balances::Trait for Test {
@@ -121,9 +142,230 @@ impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
 }
+thread_local! {
+    static MAX_PROPOSALS_POLLED: std::cell::RefCell<u32> = std::cell::RefCell::new(16);
+}
+pub struct MaxProposalsPolledPerBlock;
+impl MaxProposalsPolledPerBlock {
+    fn set(max: u32) {
+        MAX_PROPOSALS_POLLED.with(|v| *v.borrow_mut() = max);
+    }
+}
+impl Get<u32> for MaxProposalsPolledPerBlock {
+    fn get() -> u32 {
+        MAX_PROPOSALS_POLLED.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static MAX_OPEN_PROPOSALS: std::cell::RefCell<u32> = std::cell::RefCell::new(16);
+}
+pub struct MaxOpenProposalsPerMember;
+impl MaxOpenProposalsPerMember {
+    fn set(max: u32) {
+        MAX_OPEN_PROPOSALS.with(|v| *v.borrow_mut() = max);
+    }
+}
+impl Get<u32> for MaxOpenProposalsPerMember {
+    fn get() -> u32 {
+        MAX_OPEN_PROPOSALS.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static GRACE_WINDOW: std::cell::RefCell<u64> = std::cell::RefCell::new(0);
+}
+pub struct GraceWindow;
+impl GraceWindow {
+    fn set(window: u64) {
+        GRACE_WINDOW.with(|v| *v.borrow_mut() = window);
+    }
+}
+impl Get<u64> for GraceWindow {
+    fn get() -> u64 {
+        GRACE_WINDOW.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static MIN_DEPOSIT: std::cell::RefCell<u64> = std::cell::RefCell::new(20);
+}
+pub struct MinDeposit;
+impl MinDeposit {
+    fn set(amount: u64) {
+        MIN_DEPOSIT.with(|v| *v.borrow_mut() = amount);
+    }
+}
+impl Get<u64> for MinDeposit {
+    fn get() -> u64 {
+        MIN_DEPOSIT.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static SHARE_VESTING_PERIOD: std::cell::RefCell<u64> = std::cell::RefCell::new(0);
+}
+pub struct ShareVestingPeriod;
+impl ShareVestingPeriod {
+    fn set(period: u64) {
+        SHARE_VESTING_PERIOD.with(|v| *v.borrow_mut() = period);
+    }
+}
+impl Get<u64> for ShareVestingPeriod {
+    fn get() -> u64 {
+        SHARE_VESTING_PERIOD.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static MAX_BANKS_PER_ORG: std::cell::RefCell<u32> = std::cell::RefCell::new(2);
+}
+pub struct MaxBanksPerOrg;
+impl MaxBanksPerOrg {
+    fn set(max: u32) {
+        MAX_BANKS_PER_ORG.with(|v| *v.borrow_mut() = max);
+    }
+}
+impl Get<u32> for MaxBanksPerOrg {
+    fn get() -> u32 {
+        MAX_BANKS_PER_ORG.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static REQUIRE_SUPERVISOR_TO_SUMMON: std::cell::RefCell<bool> = std::cell::RefCell::new(false);
+}
+pub struct RequireSupervisorToSummon;
+impl RequireSupervisorToSummon {
+    fn set(required: bool) {
+        REQUIRE_SUPERVISOR_TO_SUMMON.with(|v| *v.borrow_mut() = required);
+    }
+}
+impl Get<bool> for RequireSupervisorToSummon {
+    fn get() -> bool {
+        REQUIRE_SUPERVISOR_TO_SUMMON.with(|v| *v.borrow())
+    }
+}
+thread_local! {
+    static SPEND_FEE: std::cell::RefCell<Permill> = std::cell::RefCell::new(Permill::zero());
+}
+pub struct SpendFee;
+impl SpendFee {
+    fn set(fee: Permill) {
+        SPEND_FEE.with(|v| *v.borrow_mut() = fee);
+    }
+}
+impl Get<Permill> for SpendFee {
+    fn get() -> Permill {
+        SPEND_FEE.with(|v| *v.borrow())
+    }
+}
 parameter_types! {
     pub const BigBank: ModuleId = ModuleId(*b"big/bank");
-    pub const MinDeposit: u64 = 20;
+    pub const SpendBond: u64 = 2;
+    pub const MinSpend: u64 = 5;
+    pub const ProcessingReward: u64 = 1;
+    pub const MaxBankNameLen: u32 = 8;
+    pub const MaxAutomatedSpendsPerBank: u32 = 2;
+    pub const MaxSplitLegs: u32 = 3;
+    pub const MaxSharesPerMember: u64 = 10;
+    pub const ProposalTtl: u64 = 100;
+    pub const MaxPageSize: u32 = 2;
+    pub const MaxActivityLog: u32 = 4;
+    pub const MaxBatchSummon: u32 = 3;
+    pub const ConvictionLockPeriod: u64 = 10;
+    pub const FeeDestination: AccountId = 101;
+}
+pub const TREASURY: AccountId = 100;
+pub struct MockTreasury;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Test>> for MockTreasury {
+    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Test>) {
+        Balances::resolve_creating(&TREASURY, amount);
+    }
+}
+thread_local! {
+    static EXTERNAL_FLAGS: std::cell::RefCell<Vec<H256>> = std::cell::RefCell::new(Vec::new());
+}
+pub struct MockFlags;
+impl MockFlags {
+    fn set(key: H256) {
+        EXTERNAL_FLAGS.with(|f| f.borrow_mut().push(key));
+    }
+}
+impl ExternalFlag<H256> for MockFlags {
+    fn is_set(key: &H256) -> bool {
+        EXTERNAL_FLAGS.with(|f| f.borrow().contains(key))
+    }
+}
+thread_local! {
+    static FORCED_OUTCOME: std::cell::RefCell<Option<VoteOutcome>> = std::cell::RefCell::new(None);
+}
+/// Delegates to the vote module unless an outcome is forced
+pub struct MockVoteMachine;
+impl MockVoteMachine {
+    fn force_outcome(outcome: VoteOutcome) {
+        FORCED_OUTCOME.with(|o| *o.borrow_mut() = Some(outcome));
+    }
+}
+impl
+    ConfigureThreshold<
+        ThresholdInput<OrgRep<u64>, XorThreshold<u64, Permill>>,
+        u32,
+        BlockNumber,
+    > for MockVoteMachine
+{
+    type ThresholdId = u64;
+    type VoteId = u64;
+    fn register_threshold(
+        t: ThresholdInput<OrgRep<u64>, XorThreshold<u64, Permill>>,
+    ) -> Result<u64, DispatchError> {
+        Vote::register_threshold(t)
+    }
+    fn invoke_threshold(
+        id: u64,
+        topic: Option<u32>,
+        duration: Option<BlockNumber>,
+    ) -> Result<u64, DispatchError> {
+        Vote::invoke_threshold(id, topic, duration)
+    }
+}
+impl GetVoteOutcome<u64> for MockVoteMachine {
+    type Outcome = VoteOutcome;
+    fn get_vote_outcome(vote_id: u64) -> Result<VoteOutcome, DispatchError> {
+        if let Some(outcome) = FORCED_OUTCOME.with(|o| *o.borrow()) {
+            Ok(outcome)
+        } else {
+            Vote::get_vote_outcome(vote_id)
+        }
+    }
+}
+impl VoteParticipation<u64, AccountId, u64> for MockVoteMachine {
+    fn turnout(vote_id: u64) -> Option<(u64, u64)> {
+        Vote::turnout(vote_id)
+    }
+    fn tally(vote_id: u64) -> Option<(u64, u64)> {
+        Vote::tally(vote_id)
+    }
+    fn awaits_vote(vote_id: u64, who: &AccountId) -> bool {
+        Vote::awaits_vote(vote_id, who)
+    }
+    fn voters(vote_id: u64) -> Vec<AccountId> {
+        Vote::voters(vote_id)
+    }
+}
+impl CloseVote<u64> for MockVoteMachine {
+    fn close_vote(vote_id: u64) -> DispatchResult {
+        Vote::close_vote(vote_id)
+    }
+}
+impl ConvictionVote<u64, u64, AccountId, BlockNumber> for MockVoteMachine {
+    fn enable_conviction(
+        vote_id: u64,
+        org: u64,
+        lock_period: BlockNumber,
+    ) -> DispatchResult {
+        Vote::enable_conviction(vote_id, org, lock_period)
+    }
+    fn conviction_locked_until(
+        org: u64,
+        who: &AccountId,
+    ) -> Option<BlockNumber> {
+        Vote::conviction_locked_until(org, who)
+    }
 }
 impl Trait for Test {
     type Event = TestEvent;
@@ -133,6 +375,30 @@ impl Trait for Test {
     type SpendId = u64;
     type MemId = u64;
     type MinDeposit = MinDeposit;
+    type SpendBond = SpendBond;
+    type MinSpend = MinSpend;
+    type ProcessingReward = ProcessingReward;
+    type MaxBanksPerOrg = MaxBanksPerOrg;
+    type MaxBankNameLen = MaxBankNameLen;
+    type MaxAutomatedSpendsPerBank = MaxAutomatedSpendsPerBank;
+    type MaxSplitLegs = MaxSplitLegs;
+    type MaxSharesPerMember = MaxSharesPerMember;
+    type MaxOpenProposalsPerMember = MaxOpenProposalsPerMember;
+    type GraceWindow = GraceWindow;
+    type WeightInfo = ();
+    type MaxProposalsPolledPerBlock = MaxProposalsPolledPerBlock;
+    type ProposalTtl = ProposalTtl;
+    type MaxPageSize = MaxPageSize;
+    type MaxActivityLog = MaxActivityLog;
+    type MaxBatchSummon = MaxBatchSummon;
+    type ShareVestingPeriod = ShareVestingPeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
+    type RequireSupervisorToSummon = RequireSupervisorToSummon;
+    type SpendFee = SpendFee;
+    type FeeDestination = FeeDestination;
+    type SlashedBondTreasury = MockTreasury;
+    type ExternalFlags = MockFlags;
+    type VoteMachine = MockVoteMachine;
 }
 pub type System = frame_system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -140,7 +406,8 @@ pub type Org = org::Module<Test>;
 pub type Vote = vote::Module<Test>;
 pub type Bank = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64, u64, u64, u64, u64> {
+fn get_last_event(
+) -> RawEvent<u64, u64, H256, u64, u64, u64, u64, u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -160,42 +427,149 @@ fn run_to_block(n: u64) {
     while System::block_number() < n {
         Bank::on_finalize(System::block_number());
         System::set_block_number(System::block_number() + 1);
+        Bank::on_initialize(System::block_number());
     }
 }
 
+/// Escrows the tribute under the applicant's own signature and has the sponsor
+/// propose them, the path for proposing another account with a tribute
+fn sponsor_applicant(
+    sponsor: u64,
+    bank_id: u64,
+    tribute: u64,
+    shares_requested: u64,
+    applicant: u64,
+) -> DispatchResult {
+    Bank::applicant_submits_membership_request(
+        Origin::signed(applicant),
+        bank_id,
+        tribute,
+        shares_requested,
+    )?;
+    Bank::member_sponsors_request(
+        Origin::signed(sponsor),
+        bank_id,
+        <RequestNonceMap<Test>>::get(bank_id),
+    )
+}
+
+/// Summons bank 1 for org 1 with a 50 deposit, account 1 as controller and a
+/// simple majority of shares to pass
+fn summon_default_bank() {
+    let threshold = ThresholdInput::new(
+        OrgRep::Equal(1),
+        XorThreshold::Percent(Threshold::new(Permill::from_percent(50), None)),
+    );
+    assert_ok!(Bank::summon(
+        Origin::signed(1),
+        1,
+        50,
+        Some(1),
+        threshold,
+        None
+    ));
+}
+
 fn new_test_ext() -> sp_io::TestExternalities {
-    let mut t = frame_system::GenesisConfig::default()
-        .build_storage::<Test>()
-        .unwrap();
-    pallet_balances::GenesisConfig::<Test> {
-        balances: vec![
-            (1, 100),
-            (2, 98),
-            (3, 200),
-            (4, 75),
-            (5, 10),
-            (6, 69),
-            (7, 77),
-        ],
+    ExtBuilder::default().build()
+}
+
+/// Configures the mock genesis, defaulting to the setup every test assumes
+pub struct ExtBuilder {
+    balances: Vec<(AccountId, u64)>,
+    min_deposit: u64,
+    spend_poll_frequency: BlockNumber,
+    member_poll_frequency: BlockNumber,
+    banks: Vec<(AccountId, u64, u64, Option<AccountId>, Permill)>,
+    initial_members: Vec<(u64, AccountId, u64)>,
+    spends: Vec<(u64, AccountId, u64, AccountId)>,
+}
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self {
+            balances: vec![
+                (1, 100),
+                (2, 98),
+                (3, 200),
+                (4, 75),
+                (5, 10),
+                (6, 69),
+                (7, 77),
+            ],
+            min_deposit: 20,
+            spend_poll_frequency: 7,
+            member_poll_frequency: 7,
+            banks: Vec::new(),
+            initial_members: Vec::new(),
+            spends: Vec::new(),
+        }
     }
-    .assimilate_storage(&mut t)
-    .unwrap();
-    org::GenesisConfig::<Test> {
-        sudo: 1,
-        doc: 1738,
-        mems: vec![1, 2, 3, 4, 5, 6],
+}
+impl ExtBuilder {
+    pub fn balances(mut self, balances: Vec<(AccountId, u64)>) -> Self {
+        self.balances = balances;
+        self
     }
-    .assimilate_storage(&mut t)
-    .unwrap();
-    GenesisConfig::<Test> {
-        spend_poll_frequency: 7,
-        member_poll_frequency: 7,
+    pub fn min_deposit(mut self, min_deposit: u64) -> Self {
+        self.min_deposit = min_deposit;
+        self
+    }
+    pub fn spend_poll_frequency(mut self, frequency: BlockNumber) -> Self {
+        self.spend_poll_frequency = frequency;
+        self
+    }
+    pub fn member_poll_frequency(mut self, frequency: BlockNumber) -> Self {
+        self.member_poll_frequency = frequency;
+        self
+    }
+    /// Summons a bank at genesis: (opener, org, deposit, controller, passage threshold)
+    pub fn bank(
+        mut self,
+        bank: (AccountId, u64, u64, Option<AccountId>, Permill),
+    ) -> Self {
+        self.banks.push(bank);
+        self
+    }
+    /// Issues shares in a genesis bank's org: (bank, member, shares)
+    pub fn member(mut self, member: (u64, AccountId, u64)) -> Self {
+        self.initial_members.push(member);
+        self
+    }
+    /// Opens a spend in a genesis bank: (bank, proposer, amount, dest)
+    pub fn spend(mut self, spend: (u64, AccountId, u64, AccountId)) -> Self {
+        self.spends.push(spend);
+        self
+    }
+    pub fn build(self) -> sp_io::TestExternalities {
+        MinDeposit::set(self.min_deposit);
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Test>()
+            .unwrap();
+        pallet_balances::GenesisConfig::<Test> {
+            balances: self.balances,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        org::GenesisConfig::<Test> {
+            sudo: 1,
+            doc: 1738,
+            mems: vec![1, 2, 3, 4, 5, 6],
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        GenesisConfig::<Test> {
+            spend_poll_frequency: self.spend_poll_frequency,
+            member_poll_frequency: self.member_poll_frequency,
+            banks: self.banks,
+            initial_members: self.initial_members,
+            spends: self.spends,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+        let mut ext: sp_io::TestExternalities = t.into();
+        ext.execute_with(|| System::set_block_number(1));
+        ext
     }
-    .assimilate_storage(&mut t)
-    .unwrap();
-    let mut ext: sp_io::TestExternalities = t.into();
-    ext.execute_with(|| System::set_block_number(1));
-    ext
 }
 
 #[test]
@@ -222,15 +596,36 @@ fn summon_works() {
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
         assert_noop!(
-            Bank::summon(Origin::signed(1), 1, 19, None, threshold.clone()),
+            Bank::summon(
+                Origin::signed(1),
+                1,
+                19,
+                None,
+                threshold.clone(),
+                None
+            ),
             Error::<Test>::CannotOpenBankAccountIfDepositIsBelowModuleMinimum
         );
         assert_noop!(
-            Bank::summon(Origin::signed(5), 1, 21, None, threshold.clone()),
+            Bank::summon(
+                Origin::signed(5),
+                1,
+                21,
+                None,
+                threshold.clone(),
+                None
+            ),
             Error::<Test>::InsufficientBalanceToFundBankOpen
         );
         assert_noop!(
-            Bank::summon(Origin::signed(70), 1, 21, None, threshold.clone()),
+            Bank::summon(
+                Origin::signed(70),
+                1,
+                21,
+                None,
+                threshold.clone(),
+                None
+            ),
             Error::<Test>::NotPermittedToOpenBankAccountForOrg
         );
         let false_threshold = ThresholdInput::new(
@@ -238,11 +633,18 @@ fn summon_works() {
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
         assert_noop!(
-            Bank::summon(Origin::signed(1), 1, 20, None, false_threshold),
+            Bank::summon(Origin::signed(1), 1, 20, None, false_threshold, None),
             Error::<Test>::ThresholdCannotBeSetForOrg
         );
-        assert_ok!(Bank::summon(Origin::signed(1), 1, 20, None, threshold));
-        let expected_event = RawEvent::AccountOpened(1, 1, 20, 1, None);
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            None,
+            threshold,
+            None
+        ));
+        let expected_event = RawEvent::AccountOpened(1, 1, 20, 1, None, None);
         assert_eq!(get_last_event(), expected_event);
     });
 }
@@ -251,27 +653,53 @@ fn summon_works() {
 fn propose_works() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            Bank::propose_spend(Origin::signed(1), 1, 19, 1),
+            Bank::propose_spend(Origin::signed(1), 1, 19, 1, None),
             Error::<Test>::BankMustExistToProposeFrom
         );
         let threshold = ThresholdInput::new(
             OrgRep::Equal(1),
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
-        assert_ok!(Bank::summon(Origin::signed(1), 1, 20, None, threshold),);
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            None,
+            threshold,
+            None
+        ),);
         assert_noop!(
-            Bank::propose_spend(Origin::signed(7), 1, 10, 7),
+            Bank::propose_spend(Origin::signed(7), 1, 10, 7, None),
             Error::<Test>::MustBeMemberToSponsorProposal
         );
         assert_noop!(
-            Bank::propose_member(Origin::signed(7), 1, 0, 100, 7),
+            Bank::propose_member(
+                Origin::signed(7),
+                1,
+                0,
+                100,
+                0,
+                7,
+                None,
+                None
+            ),
             Error::<Test>::MustBeMemberToSponsorProposal
         );
-        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7),);
-        let expected_event = RawEvent::SpendProposed(1, 1, 1, 10, 7);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None),);
+        let expected_event = RawEvent::SpendProposed(1, 1, 1, 10, 7, 0, None);
         assert_eq!(get_last_event(), expected_event);
-        assert_ok!(Bank::propose_member(Origin::signed(1), 1, 10, 5, 7),);
-        let expected_event = RawEvent::MemberProposed(1, 1, 1, 10, 5, 7);
+        assert_ok!(Bank::propose_member(
+            Origin::signed(1),
+            1,
+            10,
+            5,
+            0,
+            1,
+            None,
+            None
+        ));
+        let expected_event =
+            RawEvent::MemberProposed(1, 1, 1, 10, 5, 0, 1, 0, None);
         assert_eq!(get_last_event(), expected_event);
     });
 }
@@ -291,7 +719,14 @@ fn trigger_vote_works() {
             OrgRep::Equal(1),
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
-        assert_ok!(Bank::summon(Origin::signed(1), 1, 20, None, threshold),);
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            None,
+            threshold,
+            None
+        ),);
         assert_noop!(
             Bank::spend_trigger_vote(Origin::signed(1), 1, 1),
             Error::<Test>::CannotTriggerVoteIfProposalDNE
@@ -300,7 +735,7 @@ fn trigger_vote_works() {
             Bank::member_trigger_vote(Origin::signed(1), 1, 1),
             Error::<Test>::CannotTriggerVoteIfProposalDNE
         );
-        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7),);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None),);
         assert_noop!(
             Bank::spend_trigger_vote(Origin::signed(7), 1, 1),
             Error::<Test>::NotPermittedToTriggerVoteForBankAccount
@@ -312,7 +747,7 @@ fn trigger_vote_works() {
             Bank::member_trigger_vote(Origin::signed(1), 1, 1),
             Error::<Test>::CannotTriggerVoteIfProposalDNE
         );
-        assert_ok!(Bank::propose_member(Origin::signed(1), 1, 10, 5, 7),);
+        assert_ok!(sponsor_applicant(1, 1, 10, 5, 7));
         assert_noop!(
             Bank::member_trigger_vote(Origin::signed(7), 1, 1),
             Error::<Test>::NotPermittedToTriggerVoteForBankAccount
@@ -337,15 +772,16 @@ fn spend_governance_works() {
         assert_ok!(Bank::summon(
             Origin::signed(1),
             1,
-            20,
+            30,
             Some(1),
-            threshold.clone()
+            threshold.clone(),
+            None
         ),);
         assert_noop!(
             Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1),
             Error::<Test>::CannotSudoApproveSpendProposalIfSpendProposalDNE
         );
-        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7),);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None),);
         assert_noop!(
             Bank::sudo_approve_spend_proposal(Origin::signed(2), 1, 1),
             Error::<Test>::NotPermittedToSudoApproveForBankAccount
@@ -357,9 +793,20 @@ fn spend_governance_works() {
             Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1),
             Error::<Test>::CannotApproveAlreadyApprovedSpendProposal
         );
+        // up to two banks per org
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            None,
+            threshold.clone(),
+            None
+        ));
+        assert_eq!(Bank::org_bank_count(1), 2);
+        assert_eq!(Bank::get_org_banks(1), Ok(vec![1, 2]));
         assert_noop!(
-            Bank::summon(Origin::signed(1), 1, 50, None, threshold),
-            Error::<Test>::LimitOfOneMolochPerOrg
+            Bank::summon(Origin::signed(1), 1, 20, None, threshold, None),
+            Error::<Test>::CannotOpenBankAccountForOrgIfBankCountExceedsLimitPerOrg
         );
         // register second org, same as first
         let threshold2 = ThresholdInput::new(
@@ -371,10 +818,17 @@ fn spend_governance_works() {
             None,
             10
         ));
-        assert_ok!(Bank::summon(Origin::signed(1), 2, 50, None, threshold2));
-        assert_ok!(Bank::propose_spend(Origin::signed(3), 2, 20, 7),);
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            50,
+            None,
+            threshold2,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(3), 3, 20, 7, None),);
         System::set_block_number(22);
-        assert_ok!(Bank::spend_trigger_vote(Origin::signed(5), 2, 1));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(5), 3, 1));
         for i in 1u64..7u64 {
             let i_origin = Origin::signed(i);
             assert_ok!(Vote::submit_vote(
@@ -390,6 +844,58 @@ fn spend_governance_works() {
     });
 }
 
+#[test]
+fn spend_lifecycle_end_to_end() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        // approved spend pays the destination
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert_eq!(Bank::bank_balance(1), 40);
+        // rejected spend leaves the funds in the bank
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 15, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        run_to_block(15);
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::Rejected(2)
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert_eq!(Bank::bank_balance(1), 40);
+        // nonexistent proposals cannot be triggered or processed
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(1), 1, 9),
+            Error::<Test>::CannotTriggerVoteIfProposalDNE
+        );
+        assert_noop!(
+            Bank::member_processes_spend(Origin::signed(1), 1, 9),
+            Error::<Test>::CannotProcessProposalThatDNE
+        );
+    });
+}
+
 #[test]
 fn member_governance_works() {
     new_test_ext().execute_with(|| {
@@ -397,8 +903,15 @@ fn member_governance_works() {
             OrgRep::Equal(1),
             XorThreshold::Percent(Threshold::new(Permill::one(), None)),
         );
-        assert_ok!(Bank::summon(Origin::signed(1), 1, 20, Some(1), threshold),);
-        assert_ok!(Bank::propose_member(Origin::signed(2), 1, 10, 1, 7),);
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            Some(1),
+            threshold,
+            None
+        ),);
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
         assert_ok!(Bank::member_trigger_vote(Origin::signed(5), 1, 1));
         System::set_block_number(22);
         for i in 1u64..7u64 {
@@ -421,3 +934,4642 @@ fn member_governance_works() {
         assert_eq!(Org::outstanding_shares(1), 7);
     });
 }
+
+#[test]
+fn dest_allowlist_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            Some(1),
+            threshold,
+            None
+        ));
+        // flag off by default so any destination is accepted
+        assert!(!Bank::require_dest_allowlist(1));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_noop!(
+            Bank::set_require_dest_allowlist(Origin::signed(2), 1, true),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_noop!(
+            Bank::add_to_dest_allowlist(Origin::signed(1), 2, 7),
+            Error::<Test>::CannotConfigureBankThatDNE
+        );
+        assert_ok!(Bank::set_require_dest_allowlist(
+            Origin::signed(1),
+            1,
+            true
+        ));
+        assert_eq!(get_last_event(), RawEvent::DestAllowlistRequired(1, true));
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 10, 7, None),
+            Error::<Test>::SpendDestinationNotAllowlisted
+        );
+        assert_ok!(Bank::add_to_dest_allowlist(Origin::signed(1), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::DestAllowlisted(1, 7));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 10, 8, None),
+            Error::<Test>::SpendDestinationNotAllowlisted
+        );
+        assert_ok!(Bank::remove_from_dest_allowlist(Origin::signed(1), 1, 7));
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 10, 7, None),
+            Error::<Test>::SpendDestinationNotAllowlisted
+        );
+        // turning the flag off accepts non-allowlisted destinations again
+        assert_ok!(Bank::set_require_dest_allowlist(
+            Origin::signed(1),
+            1,
+            false
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 8, None));
+    });
+}
+
+#[test]
+fn vote_failing_quorum_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(30),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_bank_quorum(
+                Origin::signed(2),
+                1,
+                Some(Permill::from_percent(50))
+            ),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_bank_quorum(
+            Origin::signed(1),
+            1,
+            Some(Permill::from_percent(50))
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        // 2 of 6 passes the 30% support threshold but misses the 50% quorum
+        for i in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::FailedQuorum(1)
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::ProposalFailedQuorum(1, 1))
+        }));
+        assert_eq!(Balances::total_balance(&7), 77);
+        // a fresh vote can be triggered on the quorum-failed proposal
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(15);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+fn reject_spend(spend_id: u64, vote_id: u64, poll_block: u64) {
+    assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, spend_id));
+    for i in 1u64..4u64 {
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(i),
+            vote_id,
+            VoterView::Against,
+            None
+        ));
+    }
+    run_to_block(poll_block);
+    assert_eq!(
+        Bank::spend_props(1, spend_id).unwrap().state(),
+        SpendState::Rejected(vote_id)
+    );
+}
+
+#[test]
+fn rejected_spend_bond_destination_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_eq!(Bank::slashed_bond_dest(1), SlashedBondDestination::Bank);
+        let issuance = Balances::total_issuance();
+        // slashed into the bank by default
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_eq!(Bank::spend_bonds(1, 1), Some((2, 2)));
+        reject_spend(1, 1, 8);
+        assert!(Bank::spend_bonds(1, 1).is_none());
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::total_balance(&2), 96);
+        assert_eq!(Bank::bank_balance(1), 52);
+        assert_eq!(Balances::total_issuance(), issuance);
+        // burned
+        assert_noop!(
+            Bank::set_slashed_bond_destination(
+                Origin::signed(2),
+                1,
+                SlashedBondDestination::Burn
+            ),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_slashed_bond_destination(
+            Origin::signed(1),
+            1,
+            SlashedBondDestination::Burn
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        reject_spend(2, 2, 15);
+        assert_eq!(Balances::total_balance(&2), 94);
+        assert_eq!(Bank::bank_balance(1), 52);
+        assert_eq!(Balances::total_issuance(), issuance - 2);
+        // sent to the treasury handler
+        assert_ok!(Bank::set_slashed_bond_destination(
+            Origin::signed(1),
+            1,
+            SlashedBondDestination::Treasury
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        reject_spend(3, 3, 22);
+        assert_eq!(Balances::total_balance(&2), 92);
+        assert_eq!(Balances::total_balance(&TREASURY), 2);
+        assert_eq!(Balances::total_issuance(), issuance - 2);
+        let expected_event = TestEvent::bank(RawEvent::SpendBondSlashed(
+            1,
+            3,
+            2,
+            2,
+            SlashedBondDestination::Treasury,
+        ));
+        assert!(System::events()
+            .into_iter()
+            .any(|r| r.event == expected_event));
+        // bond is returned when the spend is approved
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 4));
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::total_balance(&2), 92);
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+#[test]
+fn bank_members_roster_works() {
+    new_test_ext().execute_with(|| {
+        assert!(Bank::bank_members(1, 0, 10).is_empty());
+        let weighted = vec![(6, 5), (2, 3), (4, 1), (1, 10), (3, 2)];
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(weighted),
+            None,
+            10
+        ));
+        let threshold = ThresholdInput::new(
+            OrgRep::Weighted(2),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            20,
+            None,
+            threshold,
+            None
+        ));
+        let expected = vec![(1, 10), (2, 3), (3, 2), (4, 1), (6, 5)];
+        assert_eq!(Bank::bank_members(1, 0, 10), expected);
+        // pages cover every member exactly once
+        let mut paged = Vec::new();
+        for start in (0u32..6u32).step_by(2) {
+            let page = Bank::bank_members(1, start, 2);
+            assert!(page.len() <= 2);
+            paged.extend(page);
+        }
+        assert_eq!(paged, expected);
+        assert!(Bank::bank_members(1, 5, 2).is_empty());
+    });
+}
+
+#[test]
+fn share_weighted_threshold_lets_large_holder_pass_spend() {
+    new_test_ext().execute_with(|| {
+        let weighted = vec![(1, 10), (2, 1), (3, 1), (4, 1), (5, 1)];
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(weighted),
+            None,
+            10
+        ));
+        let rule = XorThreshold::Percent(Threshold::new(
+            Permill::from_percent(60),
+            None,
+        ));
+        // the same rule, counted by shares in the first bank and by heads in the second
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            30,
+            None,
+            ThresholdInput::new(OrgRep::Weighted(2), rule.clone()),
+            None
+        ));
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            30,
+            None,
+            ThresholdInput::new(OrgRep::Equal(2), rule),
+            None
+        ));
+        for bank_id in 1u64..3u64 {
+            assert_ok!(Bank::propose_spend(
+                Origin::signed(1),
+                bank_id,
+                10,
+                7,
+                None
+            ));
+            assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), bank_id, 1));
+        }
+        for vote_id in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(1),
+                vote_id,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(
+            Bank::spend_props(2, 1).unwrap().state(),
+            SpendState::Voting(2)
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+#[test]
+fn reproposal_cooloff_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_reproposal_cooloff(Origin::signed(2), 1, 10),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_reproposal_cooloff(Origin::signed(1), 1, 10));
+        assert_eq!(get_last_event(), RawEvent::ReproposalCooloffSet(1, 10));
+        // identical spend rejected at block 7 is blocked until block 17
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        reject_spend(1, 1, 8);
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(3), 1, 10, 7, None),
+            Error::<Test>::ReproposalTooSoon
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(3), 1, 11, 7, None));
+        System::set_block_number(17);
+        assert_ok!(Bank::propose_spend(Origin::signed(3), 1, 10, 7, None));
+        // identical membership proposal rejected at block 21 is blocked until block 31
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        run_to_block(22);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::Rejected(2)
+        );
+        assert_noop!(
+            Bank::propose_member(Origin::signed(3), 1, 0, 1, 0, 7, None, None),
+            Error::<Test>::ReproposalTooSoon
+        );
+        assert_ok!(sponsor_applicant(3, 1, 5, 2, 7));
+        // expired rejections are cleared at the block they expire
+        let key = Bank::member_key(&7, 1);
+        assert_eq!(Bank::recent_rejections(1, key), Some(31));
+        run_to_block(30);
+        assert_eq!(Bank::recent_rejections(1, key), Some(31));
+        run_to_block(31);
+        assert!(Bank::recent_rejections(1, key).is_none());
+        assert_ok!(sponsor_applicant(3, 1, 5, 1, 7));
+    });
+}
+
+#[test]
+fn zero_reproposal_cooloff_allows_immediate_reproposal() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        reject_spend(1, 1, 8);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+    });
+}
+
+#[test]
+fn conditional_spend_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        // approved at block 7 but only executes from block 20
+        assert_ok!(Bank::member_proposes_conditional_spend(
+            Origin::signed(2),
+            1,
+            10,
+            7,
+            SpendCondition::AfterBlock(20)
+        ));
+        assert_eq!(
+            Bank::spend_conditions(1, 1),
+            Some(SpendCondition::AfterBlock(20))
+        );
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..7u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        let pending = TestEvent::bank(RawEvent::ConditionalSpendPending(1, 1));
+        assert!(System::events().into_iter().any(|r| r.event == pending));
+        run_to_block(15);
+        assert_eq!(Balances::total_balance(&7), 77);
+        run_to_block(22);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert!(Bank::spend_conditions(1, 1).is_none());
+        let executed =
+            TestEvent::bank(RawEvent::ConditionalSpendExecuted(1, 1, 10, 7));
+        assert!(System::events().into_iter().any(|r| r.event == executed));
+        // sudo approval also waits on the condition
+        let flag = H256::repeat_byte(1);
+        assert_ok!(Bank::member_proposes_conditional_spend(
+            Origin::signed(2),
+            1,
+            5,
+            7,
+            SpendCondition::IfExternalFlag(flag)
+        ));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        run_to_block(29);
+        assert_eq!(Balances::total_balance(&7), 87);
+        MockFlags::set(flag);
+        run_to_block(36);
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 92);
+    });
+}
+
+#[test]
+fn ragequit_fee_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            1,
+            60,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_eq!(Bank::ragequit_fee(1), Permill::zero());
+        // no fee by default, 1/6 of 60
+        assert_ok!(Bank::burn_shares(Origin::signed(2), 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(2, 1, 1, 10, 0));
+        assert_eq!(Balances::total_balance(&2), 108);
+        assert_eq!(Bank::bank_balance(1), 50);
+        assert_noop!(
+            Bank::set_ragequit_fee(
+                Origin::signed(2),
+                1,
+                Permill::from_percent(10)
+            ),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_ragequit_fee(
+            Origin::signed(1),
+            1,
+            Permill::from_percent(10)
+        ));
+        // 10% of the 1/5 claim on 50 stays in the bank
+        assert_ok!(Bank::burn_shares(Origin::signed(4), 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(4, 1, 1, 9, 1));
+        assert_eq!(Balances::total_balance(&4), 84);
+        assert_eq!(Bank::bank_balance(1), 41);
+    });
+}
+
+#[test]
+fn ragequit_leaves_funds_reserved_by_live_spend_votes() {
+    new_test_ext().execute_with(|| {
+        MinDeposit::set(5);
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            1,
+            60,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 54, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(Bank::bank_reserved_balance(1), 54);
+        // 1/6 of the 6 left unreserved
+        assert_ok!(Bank::burn_shares(Origin::signed(6), 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(6, 1, 1, 1, 0));
+        assert_eq!(Balances::total_balance(&6), 70);
+        assert_eq!(Bank::spend_reservations(1, 1), Some(54));
+        assert_eq!(Bank::bank_reserved_balance(1), 54);
+        // shares are kept if the claim cannot be paid
+        Balances::set_lock(
+            *b"testlock",
+            &Bank::bank_account_id(1),
+            5,
+            WithdrawReasons::all(),
+        );
+        assert_noop!(
+            Bank::burn_shares(Origin::signed(5), 1, 20),
+            pallet_balances::Error::<Test>::LiquidityRestrictions
+        );
+        assert_eq!(Org::outstanding_shares(1), 5);
+        assert!(Org::is_member_of_group(1, &5));
+    });
+}
+
+#[test]
+fn pending_actions_for_member_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        // spend 1 (vote 1) not voted, spend 2 (vote 2) voted, spend 3 not in a vote
+        for _ in 0..3 {
+            assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 5, 7, None));
+        }
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::Against,
+            None
+        ));
+        // member proposal 1 (vote 3) not voted
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        let expected = vec![
+            PendingAction::VoteOnSpend(1, 1),
+            PendingAction::VoteOnMember(1, 3),
+        ];
+        assert_eq!(Bank::pending_actions_for_member(1, 2, 0, 10), expected);
+        assert_eq!(
+            Bank::pending_actions_for_member(1, 2, 1, 1),
+            vec![PendingAction::VoteOnMember(1, 3)]
+        );
+        // the member acts on everything outstanding
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(2),
+            3,
+            VoterView::InFavor,
+            None
+        ));
+        assert!(Bank::pending_actions_for_member(1, 2, 0, 10).is_empty());
+        // non-members have nothing to act on
+        assert!(Bank::pending_actions_for_member(1, 7, 0, 10).is_empty());
+    });
+}
+
+#[test]
+fn root_force_closes_bank_without_controller() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_noop!(
+            Bank::sudo_force_close_bank(Origin::signed(1), 1, 20, 20),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bank::sudo_force_close_bank(Origin::root(), 2, 20, 20),
+            Error::<Test>::CannotCloseBankThatDNE
+        );
+        let issuance = Balances::total_issuance();
+        // live proposals do not block the force close
+        assert_ok!(Bank::sudo_force_close_bank(Origin::root(), 1, 20, 20));
+        assert_eq!(get_last_event(), RawEvent::BankForceClosed(1, 1));
+        assert!(Bank::bank_stores(1).is_none());
+        assert!(Bank::spend_props(1, 1).is_none());
+        // 49 above the existential deposit splits into 8 each, the 2 left are burned
+        assert_eq!(Balances::total_balance(&3), 208);
+        assert_eq!(Balances::total_balance(&6), 77);
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 0);
+        assert_eq!(Balances::total_issuance(), issuance - 2);
+    });
+}
+
+#[test]
+fn close_sweeps_dust_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(vec![(1, 1), (3, 1)]),
+            None,
+            10
+        ));
+        let threshold = ThresholdInput::new(
+            OrgRep::Weighted(2),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        // 27 above the existential deposit splits into 13 each, leaving 2 behind
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            2,
+            28,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_eq!(Bank::close_dust_dest(1), CloseDustDestination::Closer);
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            false,
+            20,
+            20
+        ));
+        let swept = TestEvent::bank(RawEvent::CloseDustSwept(
+            1,
+            2,
+            CloseDustDestination::Closer,
+        ));
+        assert!(System::events().into_iter().any(|r| r.event == swept));
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 0);
+        assert_eq!(Balances::total_balance(&1), 115);
+        assert_eq!(Balances::total_balance(&3), 185);
+        // burned
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            2,
+            28,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_close_dust_destination(
+                Origin::signed(3),
+                2,
+                CloseDustDestination::Burn
+            ),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_close_dust_destination(
+            Origin::signed(1),
+            2,
+            CloseDustDestination::Burn
+        ));
+        let issuance = Balances::total_issuance();
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            2,
+            false,
+            20,
+            20
+        ));
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(2)), 0);
+        assert_eq!(Balances::total_issuance(), issuance - 2);
+        assert_eq!(Balances::total_balance(&1), 128);
+        assert_eq!(Balances::total_balance(&3), 170);
+    });
+}
+
+#[test]
+fn vote_machine_indirection_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        // nobody votes but the vote machine reports approval
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+fn approve_member(prop_id: u64, vote_id: u64, poll_block: u64) {
+    assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, prop_id));
+    for i in 1u64..6u64 {
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(i),
+            vote_id,
+            VoterView::InFavor,
+            None
+        ));
+    }
+    run_to_block(poll_block);
+}
+
+#[test]
+fn max_total_shares_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::set_max_total_shares(Origin::signed(2), 1, Some(8)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_max_total_shares(Origin::signed(1), 1, Some(8)));
+        assert_eq!(Bank::bank_shares(1), Some((6, Some(8))));
+        // fits under the cap
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        approve_member(1, 1, 8);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert_eq!(Bank::bank_shares(1), Some((7, Some(8))));
+        assert_eq!(Balances::total_balance(&7), 67);
+        // would push total shares to 9 so the tribute is not taken
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(2, 2, 15);
+        assert_eq!(
+            Bank::member_props(1, 2).unwrap().state(),
+            ProposalState::ApprovedButNotExecuted
+        );
+        assert_eq!(Bank::bank_shares(1), Some((7, Some(8))));
+        assert_eq!(Balances::total_balance(&7), 67);
+        // removing the cap allows it
+        assert_ok!(Bank::set_max_total_shares(Origin::signed(1), 1, None));
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(3, 3, 22);
+        assert_eq!(
+            Bank::member_props(1, 3).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert_eq!(Bank::bank_shares(1), Some((9, None)));
+        assert_eq!(Balances::total_balance(&7), 57);
+    });
+}
+
+#[test]
+fn claim_loot_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            1,
+            60,
+            Some(1),
+            threshold,
+            None
+        ));
+        // 7 pays 10 into the bank for 4 loot and no voting shares
+        assert_ok!(Balances::transfer(
+            Origin::signed(7),
+            Bank::bank_account_id(1),
+            10
+        ));
+        assert_ok!(Bank::propose_member(
+            Origin::signed(2),
+            1,
+            0,
+            0,
+            4,
+            7,
+            None,
+            None
+        ));
+        approve_member(1, 1, 8);
+        assert_eq!(Bank::loot(1, 7), 4);
+        assert_eq!(Bank::total_loot(1), 4);
+        assert!(!Org::is_member_of_group(1, &7));
+        assert_eq!(Bank::bank_balance(1), 70);
+        assert_noop!(
+            Bank::claim_loot(Origin::signed(7), 1, 5),
+            Error::<Test>::InsufficientLootToClaim
+        );
+        assert_noop!(
+            Bank::claim_loot(Origin::signed(2), 1, 1),
+            Error::<Test>::InsufficientLootToClaim
+        );
+        // 2 loot of a 6 share + 4 loot base claims 1/5 of 70
+        assert_ok!(Bank::claim_loot(Origin::signed(7), 1, 2));
+        assert_eq!(get_last_event(), RawEvent::LootClaimed(1, 7, 2, 14));
+        assert_eq!(Balances::total_balance(&7), 81);
+        assert_eq!(Bank::bank_balance(1), 56);
+        assert_eq!(Bank::loot(1, 7), 2);
+        assert_eq!(Bank::total_loot(1), 2);
+        assert_eq!(Org::outstanding_shares(1), 6);
+    });
+}
+
+#[test]
+fn sudo_approve_and_poll_execute_once() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // read before sudo approval, as a poll in the same block would
+        let stale = Bank::spend_props(1, 1).unwrap();
+        assert_eq!(stale.state(), SpendState::Voting(1));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(Balances::total_balance(&7), 87);
+        // the live vote is cancelled rather than left open
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::LiveVoteCancelledBySudo(1, 1, 1))
+        }));
+        assert!(Vote::vote_states(1).is_none());
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(4), 1, VoterView::InFavor, None),
+            vote::Error::<Test>::NoVoteStateForVoteRequest
+        );
+        assert_eq!(
+            Bank::poll_spend_proposal(stale),
+            Ok(SpendState::ApprovedAndExecuted)
+        );
+        run_to_block(8);
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert_eq!(Bank::bank_balance(1), 40);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_noop!(
+            Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotApproveAlreadyApprovedSpendProposal
+        );
+    });
+}
+
+#[test]
+fn proposal_fee_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_proposal_fee(Origin::signed(2), 1, 3),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_proposal_fee(Origin::signed(1), 1, 3));
+        // the fee is consumed into the bank on top of the refundable bond
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendProposed(2, 1, 1, 10, 7, 3, None)
+        );
+        assert_eq!(Bank::bank_balance(1), 53);
+        assert_eq!(Balances::free_balance(&2), 93);
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_ok!(Bank::propose_member(
+            Origin::signed(2),
+            1,
+            0,
+            1,
+            0,
+            7,
+            None,
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MemberProposed(2, 1, 1, 0, 1, 0, 7, 3, None)
+        );
+        assert_eq!(Bank::bank_balance(1), 56);
+        assert_eq!(Balances::free_balance(&2), 90);
+        // 5 can reserve the bond but not also pay the fee
+        assert_ok!(Bank::set_proposal_fee(Origin::signed(1), 1, 9));
+        assert_eq!(
+            Bank::propose_spend(Origin::signed(5), 1, 10, 7, None),
+            Err(Error::<Test>::CannotAffordProposalFee.into())
+        );
+        assert_eq!(Balances::reserved_balance(&5), 0);
+        assert_eq!(Balances::free_balance(&5), 10);
+        assert!(Bank::spend_props(1, 2).is_none());
+        assert_ok!(Bank::set_proposal_fee(Origin::signed(1), 1, 10));
+        assert_noop!(
+            Bank::propose_member(Origin::signed(5), 1, 0, 1, 0, 7, None, None),
+            Error::<Test>::CannotAffordProposalFee
+        );
+    });
+}
+
+#[test]
+fn max_vote_age_force_resolves() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_max_vote_age(Origin::signed(2), 1, Some(10)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_max_vote_age(Origin::signed(1), 1, Some(10)));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(Bank::vote_started(1), Some(1));
+        // the unanimous threshold is never reached
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // still live at block 7, 6 blocks in
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::Voting(1)
+        );
+        // force resolved at block 14, 13 blocks in
+        run_to_block(15);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::Rejected(1)
+        );
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::Rejected(2)
+        );
+        for vote_id in 1u64..3u64 {
+            let forced =
+                TestEvent::bank(RawEvent::VoteForceResolved(1, vote_id));
+            assert!(System::events().into_iter().any(|r| r.event == forced));
+            assert!(Bank::vote_started(vote_id).is_none());
+        }
+        // the bond is returned because the spend was not voted down
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::total_balance(&2), 98);
+        assert_eq!(Balances::total_balance(&7), 77);
+    });
+}
+
+#[test]
+fn member_stats_work() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_eq!(Bank::member_stats(1, 2), MemberStat::default());
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_eq!(Bank::member_stats(1, 2).spends_proposed, 2);
+        // 1..3 vote to pass the first spend
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(Bank::member_stats(1, 2).spends_passed, 1);
+        assert_eq!(Bank::member_stats(1, 3).votes_cast, 1);
+        assert_eq!(Bank::member_stats(1, 4).votes_cast, 0);
+        // 7 pays tribute, 1..5 vote
+        assert_ok!(sponsor_applicant(3, 1, 10, 1, 7));
+        approve_member(1, 2, 15);
+        assert_eq!(
+            Bank::member_stats(1, 7),
+            MemberStat {
+                tribute_paid: 10,
+                spends_proposed: 0,
+                spends_passed: 0,
+                votes_cast: 0,
+            }
+        );
+        assert_eq!(
+            Bank::member_stats(1, 2),
+            MemberStat {
+                tribute_paid: 0,
+                spends_proposed: 2,
+                spends_passed: 1,
+                votes_cast: 2,
+            }
+        );
+        assert_eq!(Bank::member_stats(1, 5).votes_cast, 1);
+    });
+}
+
+#[test]
+fn member_tribute_escrow_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            sponsor_applicant(1, 1, 78, 1, 7),
+            Error::<Test>::InsufficientBalanceToReserveTribute
+        );
+        // the tribute is escrowed while the proposal is unresolved
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        assert_eq!(Balances::free_balance(&7), 67);
+        // rejection refunds it to the applicant
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::Rejected(1)
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::TributeRefunded(1, 1, 7, 10))
+        }));
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        assert_eq!(Balances::free_balance(&7), 77);
+        assert_eq!(Bank::bank_balance(1), 50);
+        // approval moves it into the bank
+        assert_ok!(sponsor_applicant(1, 1, 10, 2, 7));
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        approve_member(2, 2, 15);
+        assert_eq!(
+            Bank::member_props(1, 2).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        assert_eq!(Balances::free_balance(&7), 67);
+        assert_eq!(Bank::bank_balance(1), 60);
+    });
+}
+
+#[test]
+fn members_cannot_escrow_tribute_for_others() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::propose_member(Origin::signed(1), 1, 10, 1, 0, 7, None, None),
+            Error::<Test>::TributeMustBeEscrowedByApplicant
+        );
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        assert_eq!(Balances::free_balance(&7), 77);
+        // members may still tribute their own funds or propose others without one
+        assert_ok!(Bank::propose_member(
+            Origin::signed(2),
+            1,
+            10,
+            1,
+            0,
+            2,
+            None,
+            None
+        ));
+        assert_eq!(Balances::reserved_balance(&2), 10);
+        assert_ok!(Bank::propose_member(
+            Origin::signed(1),
+            1,
+            0,
+            1,
+            0,
+            7,
+            None,
+            None
+        ));
+        // a tribute from someone else is escrowed by their own request
+        assert_ok!(sponsor_applicant(1, 1, 10, 2, 7));
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        assert_eq!(Bank::member_props(1, 3).unwrap().tribute(), 10);
+    });
+}
+
+#[test]
+fn trigger_vote_fails_once_org_is_empty() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        for i in 1u64..7u64 {
+            assert_ok!(Org::burn_shares(Origin::signed(1), 1, i, 1));
+        }
+        assert_eq!(Org::outstanding_shares(1), 0);
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotTriggerVoteWithNoMembers
+        );
+        assert_noop!(
+            Bank::member_trigger_vote(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotTriggerVoteWithNoMembers
+        );
+        // the controller can still move the funds
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+#[test]
+fn tribute_policy_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        // unset, any valuation is accepted
+        assert_ok!(sponsor_applicant(1, 1, 1, 5, 7));
+        assert_noop!(
+            Bank::set_tribute_policy(Origin::signed(2), 1, Some(4)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_tribute_policy(Origin::signed(1), 1, Some(4)));
+        assert_eq!(get_last_event(), RawEvent::TributePolicySet(1, Some(4)));
+        assert_eq!(Bank::min_tribute_per_share(1), Some(4));
+        assert_ok!(Bank::applicant_submits_membership_request(
+            Origin::signed(7),
+            1,
+            11,
+            3
+        ));
+        assert_noop!(
+            Bank::member_sponsors_request(Origin::signed(1), 1, 2),
+            Error::<Test>::TributeBelowMinimumPerShare
+        );
+        assert_ok!(Bank::applicant_withdraws_request(Origin::signed(7), 1, 2));
+        assert_eq!(Balances::reserved_balance(&7), 1);
+        assert_ok!(sponsor_applicant(1, 1, 12, 3, 7));
+        assert_eq!(Balances::reserved_balance(&7), 13);
+        assert_ok!(Bank::set_tribute_policy(Origin::signed(1), 1, None));
+        assert_eq!(Bank::min_tribute_per_share(1), None);
+    });
+}
+
+#[test]
+fn failed_share_issuance_refunds_tribute() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        // issuance fails once the org is gone
+        assert_ok!(Org::remove_organization(1));
+        run_to_block(8);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::ApprovedButNotExecuted
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::TributeRefunded(1, 1, 7, 10))
+        }));
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        assert_eq!(Balances::free_balance(&7), 77);
+        assert_eq!(Bank::bank_balance(1), 50);
+        assert!(Org::members(1, &7).is_none());
+        assert_eq!(Bank::member_stats(1, 7).tribute_paid, 0);
+    });
+}
+
+#[test]
+fn poke_concluded_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        for i in 1u64..4u64 {
+            assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+            assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, i));
+        }
+        // only the first two votes conclude
+        for vote_id in 1u64..3u64 {
+            for i in 1u64..4u64 {
+                assert_ok!(Vote::submit_vote(
+                    Origin::signed(i),
+                    vote_id,
+                    VoterView::InFavor,
+                    None
+                ));
+            }
+        }
+        // the bank's active list and its first spend use up the bound
+        assert_ok!(Bank::poke_concluded(Origin::signed(6), 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConcludedProposalsPoked(6, 1, true)
+        );
+        assert_ok!(Bank::poke_concluded(Origin::signed(6), 5));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConcludedProposalsPoked(6, 1, false)
+        );
+        for spend_id in 1u64..3u64 {
+            assert_eq!(
+                Bank::spend_props(1, spend_id).unwrap().state(),
+                SpendState::ApprovedAndExecuted
+            );
+        }
+        assert_eq!(
+            Bank::spend_props(1, 3).unwrap().state(),
+            SpendState::Voting(3)
+        );
+        assert_eq!(Balances::total_balance(&7), 97);
+        // poked proposals leave the active sets like polled ones
+        assert_eq!(Bank::active_spend_props(1), vec![3]);
+        assert_eq!(Bank::open_proposal_count(1, 1), 1);
+        assert_ok!(Bank::poke_concluded(Origin::signed(6), 5));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConcludedProposalsPoked(6, 0, false)
+        );
+        // so the auto poll does not resolve them a second time
+        run_to_block(8);
+        assert!(!System::events().into_iter().any(|r| {
+            matches!(
+                r.event,
+                TestEvent::bank(RawEvent::SpendProposalPolled(
+                    1,
+                    1..=2,
+                    _,
+                    _,
+                    ResolutionTrigger::Auto
+                ))
+            )
+        }));
+    });
+}
+
+#[test]
+fn bank_names_work() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_noop!(
+            Bank::summon(
+                Origin::signed(1),
+                1,
+                50,
+                Some(1),
+                threshold.clone(),
+                Some(b"treasury!".to_vec())
+            ),
+            Error::<Test>::BankNameTooLong
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            Some(b"treasury".to_vec())
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::AccountOpened(
+                1,
+                1,
+                50,
+                1,
+                Some(1),
+                Some(b"treasury".to_vec())
+            )
+        );
+        assert_eq!(
+            Bank::bank_stores(1).unwrap().name(),
+            Some(b"treasury".to_vec())
+        );
+        assert_noop!(
+            Bank::rename_bank(Origin::signed(2), 1, Some(b"grants".to_vec())),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_noop!(
+            Bank::rename_bank(
+                Origin::signed(1),
+                1,
+                Some(b"grants fund".to_vec())
+            ),
+            Error::<Test>::BankNameTooLong
+        );
+        assert_ok!(Bank::rename_bank(
+            Origin::signed(1),
+            1,
+            Some(b"grants".to_vec())
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankRenamed(1, Some(b"grants".to_vec()))
+        );
+        assert_eq!(
+            Bank::bank_stores(1).unwrap().name(),
+            Some(b"grants".to_vec())
+        );
+        assert_ok!(Bank::rename_bank(Origin::signed(1), 1, None));
+        assert_eq!(Bank::bank_stores(1).unwrap().name(), None);
+    });
+}
+
+#[test]
+fn sponsor_bond_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_sponsor_bond(Origin::signed(2), 1, 5),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_sponsor_bond(Origin::signed(1), 1, 20));
+        assert_noop!(
+            Bank::propose_member(Origin::signed(5), 1, 0, 1, 0, 7, None, None),
+            Error::<Test>::InsufficientBalanceToReserveSponsorBond
+        );
+        assert_ok!(Bank::set_sponsor_bond(Origin::signed(1), 1, 5));
+        // approval returns the bond
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().sponsor_bond(),
+            Some((2, 5))
+        );
+        assert_eq!(Balances::reserved_balance(&2), 5);
+        approve_member(1, 1, 8);
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 98);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SponsorBondReturned(1, 1, 2, 5))
+        }));
+        // rejection slashes the bond into the bank
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 6));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 2));
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::Against,
+                None
+            ));
+        }
+        run_to_block(15);
+        assert_eq!(
+            Bank::member_props(1, 2).unwrap().state(),
+            ProposalState::Rejected(2)
+        );
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 93);
+        assert_eq!(Bank::bank_balance(1), 65);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::SponsorBondSlashed(1, 2, 2, 5))
+        }));
+        // a vote that expires without being voted down returns the bond
+        assert_ok!(Bank::set_max_vote_age(Origin::signed(1), 1, Some(3)));
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 6));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 3));
+        assert_eq!(Balances::reserved_balance(&2), 5);
+        run_to_block(22);
+        assert_eq!(
+            Bank::member_props(1, 3).unwrap().state(),
+            ProposalState::Rejected(3)
+        );
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::free_balance(&2), 93);
+    });
+}
+
+#[test]
+fn delegate_triggers_votes_for_member() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_noop!(
+            Bank::set_delegate(Origin::signed(2), 1, Some(3)),
+            Error::<Test>::CannotDelegateForBankThatDNE
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(7), 1, 1),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+        assert_noop!(
+            Bank::set_delegate(Origin::signed(7), 1, Some(2)),
+            Error::<Test>::MustBeMemberToDelegate
+        );
+        assert_noop!(
+            Bank::set_delegate(Origin::signed(2), 1, Some(7)),
+            Error::<Test>::DelegateMustBeMemberOrObserver
+        );
+        assert_ok!(Bank::add_observer(Origin::signed(1), 1, 7));
+        assert_ok!(Bank::set_delegate(Origin::signed(2), 1, Some(7)));
+        assert_eq!(get_last_event(), RawEvent::DelegateSet(1, 2, Some(7)));
+        assert_eq!(Bank::delegators(1, 7), vec![2]);
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(7), 1, 1));
+        // delegations may not loop back to the delegator
+        assert_noop!(
+            Bank::set_delegate(Origin::signed(1), 1, Some(1)),
+            Error::<Test>::DelegationWouldFormCycle
+        );
+        assert_ok!(Bank::set_delegate(Origin::signed(3), 1, Some(2)));
+        assert_noop!(
+            Bank::set_delegate(Origin::signed(2), 1, Some(3)),
+            Error::<Test>::DelegationWouldFormCycle
+        );
+        // revoking the delegation removes the delegate's rights
+        assert_ok!(Bank::set_delegate(Origin::signed(2), 1, None));
+        assert_eq!(Bank::delegates(1, 2), None);
+        assert!(Bank::delegators(1, 7).is_empty());
+        assert_ok!(sponsor_applicant(1, 1, 10, 4, 7));
+        assert_noop!(
+            Bank::member_trigger_vote(Origin::signed(7), 1, 1),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+    });
+}
+
+#[test]
+fn proposals_by_filters_on_proposer() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert!(Bank::proposals_by(2, None, 10).0.is_empty());
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 3, None));
+        assert_eq!(Bank::spend_props(1, 1).unwrap().proposer(), 1);
+        let (by_two, next) = Bank::proposals_by(2, None, 10);
+        assert_eq!(by_two.len(), 2);
+        assert!(next.is_none());
+        assert!(by_two.iter().all(|(_, prop)| prop.proposer() == 2));
+        assert_eq!(Bank::proposals_by(1, None, 10).0.len(), 1);
+        // pages follow bank and spend ids
+        let (page, next) = Bank::proposals_by(2, None, 1);
+        assert_eq!(page[0].0, (1, 2));
+        assert_eq!(next, Some((1, 2)));
+        let (page, next) = Bank::proposals_by(2, next, 1);
+        assert_eq!(page[0].0, (1, 3));
+        assert!(next.is_none());
+    });
+}
+
+#[test]
+fn open_proposals_per_member_are_capped() {
+    new_test_ext().execute_with(|| {
+        MaxOpenProposalsPerMember::set(2);
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 5, 7, None));
+        assert_eq!(Bank::open_proposal_count(1, 1), 2);
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(1), 1, 6, 7, None),
+            Error::<Test>::TooManyOpenProposals
+        );
+        assert_noop!(
+            Bank::propose_member(Origin::signed(1), 1, 0, 4, 0, 7, None, None),
+            Error::<Test>::TooManyOpenProposals
+        );
+        // other members keep their own allowance
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 6, 7, None));
+        // a resolved spend frees its proposer's slot
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Bank::open_proposal_count(1, 1), 1);
+        assert_ok!(sponsor_applicant(1, 1, 10, 4, 7));
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(1), 1, 6, 7, None),
+            Error::<Test>::TooManyOpenProposals
+        );
+        // so does a cancelled one
+        assert_ok!(Bank::member_cancels_spend(Origin::signed(1), 1, 2));
+        assert_eq!(Bank::open_proposal_count(1, 1), 1);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 6, 7, None));
+    });
+}
+
+#[test]
+fn dividend_pays_holders_and_keeps_bank_open() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        // the minimum deposit stays in the bank
+        assert_noop!(
+            Bank::member_proposes_dividend(Origin::signed(2), 1, 31),
+            Error::<Test>::SpendExceedsBankBalance
+        );
+        assert_ok!(Bank::member_proposes_dividend(Origin::signed(2), 1, 30));
+        assert_eq!(get_last_event(), RawEvent::DividendProposed(2, 1, 1, 30));
+        assert!(Bank::dividend_spends(1, 1));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::DividendDistributed(1, 30))
+        }));
+        // six members with one share each are paid five apiece
+        assert_eq!(Balances::total_balance(&3), 205);
+        assert_eq!(Balances::total_balance(&6), 74);
+        assert_eq!(Bank::bank_balance(1), 20);
+        assert!(Bank::bank_stores(1).is_some());
+        assert!(!Bank::dividend_spends(1, 1));
+    });
+}
+
+#[test]
+fn dividend_waits_for_funds_reserved_by_other_spends() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::member_proposes_dividend(Origin::signed(2), 1, 25));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        // the 10 reserved for the other spend leaves only 20 above the minimum
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert!(!System::events().into_iter().any(|r| {
+            matches!(
+                r.event,
+                TestEvent::bank(RawEvent::DividendDistributed(..))
+            )
+        }));
+        assert_eq!(Balances::total_balance(&6), 69);
+        assert_eq!(Balances::total_balance(&7), 87);
+        // every holder is paid once the bank can cover the whole dividend
+        assert_ok!(Bank::member_funds_bank(Origin::signed(3), 1, 10));
+        assert_ok!(Bank::member_retries_spend_execution(
+            Origin::signed(1),
+            1,
+            1
+        ));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::DividendDistributed(1, 24))
+        }));
+        assert_eq!(Balances::total_balance(&6), 73);
+        assert_eq!(Balances::total_balance(&3), 194);
+    });
+}
+
+#[test]
+fn inter_bank_spend_funds_receiving_bank() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_ok!(Bank::summon(
+            Origin::signed(2),
+            1,
+            20,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::member_proposes_spend_to_bank(Origin::signed(2), 1, 9, 10),
+            Error::<Test>::CannotSpendToBankThatDNE
+        );
+        assert_noop!(
+            Bank::member_proposes_spend_to_bank(Origin::signed(2), 1, 1, 10),
+            Error::<Test>::CannotSpendToSameBank
+        );
+        assert_ok!(Bank::member_proposes_spend_to_bank(
+            Origin::signed(2),
+            1,
+            2,
+            25
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::InterBankSpendProposed(2, 1, 1, 2, 25)
+        );
+        assert_eq!(Bank::inter_bank_spends(1, 1), Some(2));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::InterBankSpendExecuted(
+                    1, 1, 2, 25,
+                ))
+        }));
+        assert_eq!(Bank::bank_balance(1), 25);
+        assert_eq!(Bank::bank_balance(2), 45);
+        assert_eq!(Bank::inter_bank_spends(1, 1), None);
+        // the received funds are spendable by the receiving bank
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 2, 25, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 2, 1));
+        run_to_block(15);
+        assert_eq!(Balances::total_balance(&7), 102);
+        assert_eq!(Bank::bank_balance(2), 20);
+    });
+}
+
+#[test]
+fn reserving_spend_funds_moves_free_not_total() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 25, 7, None));
+        assert_eq!(Bank::bank_free_balance(1), 50);
+        assert_eq!(Bank::bank_reserved_balance(1), 0);
+        assert_eq!(Bank::bank_available_to_spend(1), 30);
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(Bank::bank_balance(1), 50);
+        assert_eq!(Bank::bank_free_balance(1), 25);
+        assert_eq!(Bank::bank_reserved_balance(1), 25);
+        assert_eq!(Bank::bank_available_to_spend(1), 5);
+    });
+}
+
+fn batch_spec(
+    deposit: u64,
+) -> (
+    u64,
+    u64,
+    Option<u64>,
+    ThresholdInput<OrgRep<u64>, XorThreshold<u64, Permill>>,
+) {
+    let threshold = ThresholdInput::new(
+        OrgRep::Equal(1),
+        XorThreshold::Percent(Threshold::new(Permill::from_percent(50), None)),
+    );
+    (1, deposit, Some(1), threshold)
+}
+
+#[test]
+fn batch_summon_stops_at_first_failure() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bank::batch_summon(Origin::signed(1), Vec::new(), false),
+            Error::<Test>::BatchSummonMustBeNonEmpty
+        );
+        assert_noop!(
+            Bank::batch_summon(
+                Origin::signed(1),
+                vec![batch_spec(20); 4],
+                false
+            ),
+            Error::<Test>::TooManyBanksInBatchSummon
+        );
+        // the third bank exceeds the two allowed per org
+        assert_ok!(Bank::batch_summon(
+            Origin::signed(1),
+            vec![batch_spec(20), batch_spec(25), batch_spec(30)],
+            false
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BanksBatchSummoned(1, vec![1, 2], 3)
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::AccountOpened(
+                    1,
+                    2,
+                    25,
+                    1,
+                    Some(1),
+                    None,
+                ))
+        }));
+        assert_eq!(Bank::org_bank_count(1), 2);
+        assert_eq!(Bank::bank_balance(2), 25);
+        assert_eq!(Balances::free_balance(&1), 55);
+    });
+}
+
+#[test]
+fn batch_summon_all_or_nothing() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Bank::batch_summon(
+                Origin::signed(1),
+                vec![batch_spec(20), batch_spec(25), batch_spec(30)],
+                true
+            ),
+            Error::<Test>::CannotOpenBankAccountForOrgIfBankCountExceedsLimitPerOrg
+        );
+        assert_eq!(Bank::org_bank_count(1), 0);
+        assert!(Bank::bank_stores(1).is_none());
+        assert_eq!(Balances::free_balance(&1), 100);
+        assert_ok!(Bank::batch_summon(
+            Origin::signed(1),
+            vec![batch_spec(20), batch_spec(25)],
+            true
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BanksBatchSummoned(1, vec![1, 2], 2)
+        );
+        assert_eq!(Bank::org_bank_count(1), 2);
+        assert_eq!(Bank::total_bank_count(), 2);
+    });
+}
+
+#[test]
+fn can_execute_spend_dry_runs_execution() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::can_execute_spend(1, 9),
+            Error::<Test>::CannotExecuteSpendThatDNE
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 30, 7, None));
+        assert_ok!(Bank::can_execute_spend(1, 1));
+        // funds reserved for the spend still count toward paying it
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(Bank::can_execute_spend(1, 1));
+        // but not toward paying another
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 30, 6, None));
+        assert_noop!(
+            Bank::can_execute_spend(1, 2),
+            Error::<Test>::InsufficientBankBalanceToExecuteSpend
+        );
+        // nor when locks on the bank account would block the transfer
+        Balances::set_lock(
+            *b"testlock",
+            &Bank::bank_account_id(1),
+            25,
+            WithdrawReasons::all(),
+        );
+        assert_noop!(
+            Bank::can_execute_spend(1, 1),
+            pallet_balances::Error::<Test>::LiquidityRestrictions
+        );
+        assert_eq!(Bank::spend_reservations(1, 1), Some(30));
+        assert_ok!(Bank::freeze_bank(Origin::signed(1), 1));
+        assert_noop!(Bank::can_execute_spend(1, 1), Error::<Test>::BankFrozen);
+    });
+}
+
+#[test]
+fn bank_activity_log_is_capped() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::bank_activity(1),
+            vec![
+                (1, BankActivity::Opened),
+                (1, BankActivity::SpendProposed(1)),
+                (1, BankActivity::SpendVoteTriggered(1, 1)),
+            ]
+        );
+        MockVoteMachine::force_outcome(VoteOutcome::Approved);
+        run_to_block(8);
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        // the oldest entry is dropped past the cap
+        let activity = Bank::bank_activity(1)
+            .into_iter()
+            .map(|(_, activity)| activity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            activity,
+            vec![
+                BankActivity::SpendProposed(1),
+                BankActivity::SpendVoteTriggered(1, 1),
+                BankActivity::SpendResolved(1),
+                BankActivity::MemberProposed(1),
+            ]
+        );
+        assert_eq!(Bank::bank_activity(1).last().unwrap().0, 8);
+        assert!(Bank::bank_activity(2).is_empty());
+    });
+}
+
+#[test]
+fn bank_snapshot_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert!(Bank::bank_snapshot(1).is_none());
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(sponsor_applicant(1, 1, 10, 4, 7));
+        let snapshot = Bank::bank_snapshot(1).unwrap();
+        assert_eq!(snapshot.bank, Bank::bank_stores(1).unwrap());
+        assert_eq!(snapshot.balance, 50);
+        assert_eq!(snapshot.free, 40);
+        assert_eq!(snapshot.reserved, 10);
+        assert_eq!(snapshot.available, 20);
+        assert_eq!(snapshot.active_spends, vec![1]);
+        assert_eq!(snapshot.active_member_proposals, vec![1]);
+        assert_eq!(snapshot.spend_threshold, snapshot.bank.threshold_id());
+        assert_eq!(snapshot.member_threshold, snapshot.bank.threshold_id());
+        assert_eq!(snapshot.spend_poll_frequency, 7);
+        assert_eq!(snapshot.member_poll_frequency, 7);
+        assert!(Bank::bank_snapshot(2).is_none());
+    });
+}
+
+#[test]
+fn member_ownership_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_eq!(Bank::member_ownership(1, 1), Permill::zero());
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        let total: u32 = (1u64..7u64)
+            .map(|i| Bank::member_ownership(1, i).deconstruct())
+            .sum();
+        // rounding each sixth may drift a few parts per million
+        assert!((999_994..=1_000_006).contains(&total));
+        assert_eq!(Bank::member_ownership(1, 7), Permill::zero());
+        assert_ok!(sponsor_applicant(1, 1, 10, 4, 7));
+        approve_member(1, 1, 8);
+        assert_eq!(Bank::member_ownership(1, 7), Permill::from_percent(40));
+        assert_eq!(Bank::member_ownership(1, 1), Permill::from_percent(10));
+        assert_eq!(
+            Bank::member_voting_power(1, 7),
+            Some(Permill::from_percent(40))
+        );
+        assert_eq!(Bank::member_voting_power(1, 8), None);
+        assert_eq!(Bank::member_voting_power(2, 7), None);
+        let membership = Bank::bank_membership(1);
+        assert_eq!(membership.len(), 7);
+        assert!(membership.contains(&(7, 4)));
+        assert!(Bank::bank_membership(2).is_empty());
+    });
+}
+
+#[test]
+fn unregister_bank_clears_storage() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_ok!(Bank::set_sponsor_bond(Origin::signed(1), 1, 5));
+        assert_ok!(Bank::set_bank_quorum(
+            Origin::signed(1),
+            1,
+            Some(Permill::from_percent(10))
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(sponsor_applicant(3, 1, 10, 1, 7));
+        assert_ok!(Bank::propose_guild_kick(Origin::signed(2), 1, 6));
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_eq!(Balances::reserved_balance(&3), 5);
+        assert_eq!(Bank::total_bank_count(), 1);
+        assert_noop!(
+            Bank::close_org_bank_account(Origin::signed(1), 1, false, 20, 20),
+            Error::<Test>::CannotCloseBankWithLiveProposals
+        );
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            true,
+            20,
+            20
+        ));
+        assert_eq!(Bank::total_bank_count(), 0);
+        assert!(Bank::bank_stores(1).is_none());
+        assert_eq!(Bank::org_bank_count(1), 0);
+        assert_eq!(Bank::spend_nonce_map(1), 0);
+        assert_eq!(Bank::proposal_nonce_map(1), 0);
+        assert!(SpendProps::<Test>::iter_prefix(1).next().is_none());
+        assert!(MemberProps::<Test>::iter_prefix(1).next().is_none());
+        assert!(KickProps::<Test>::iter_prefix(1).next().is_none());
+        assert!(SpendBonds::<Test>::iter_prefix(1).next().is_none());
+        assert!(MemberStats::<Test>::iter_prefix(1).next().is_none());
+        assert!(Bank::vote_started(1).is_none());
+        assert!(Vote::vote_states(1).is_none());
+        assert_eq!(Bank::sponsor_bond(1), 0);
+        assert!(Bank::bank_quorum(1).is_none());
+        // live bonds are returned rather than left reserved
+        assert_eq!(Balances::reserved_balance(&2), 0);
+        assert_eq!(Balances::reserved_balance(&3), 0);
+        // the org may open a new bank
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_eq!(Bank::total_bank_count(), 1);
+    });
+}
+
+#[test]
+fn observers_can_read_but_not_act() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        let details = SpendDetail {
+            proposer: Some(2),
+            bond: Some(2),
+        };
+        assert_eq!(Bank::spend_details(1, 1, 3), Some(details.clone()));
+        assert_eq!(Bank::spend_details(1, 1, 7), None);
+        assert_noop!(
+            Bank::add_observer(Origin::signed(2), 1, 7),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::add_observer(Origin::signed(1), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::ObserverAdded(1, 7));
+        assert_eq!(Bank::spend_details(1, 1, 7), Some(details));
+        // observers are not members so every action is rejected
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(7), 1, 10, 7, None),
+            Error::<Test>::MustBeMemberToSponsorProposal
+        );
+        assert_noop!(
+            Bank::propose_member(Origin::signed(7), 1, 10, 1, 0, 7, None, None),
+            Error::<Test>::MustBeMemberToSponsorProposal
+        );
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(7), 1, 1),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(7), 1, VoterView::InFavor, None),
+            vote::Error::<Test>::SignalNotMintedForVoter
+        );
+        assert_ok!(Bank::remove_observer(Origin::signed(1), 1, 7));
+        assert_eq!(get_last_event(), RawEvent::ObserverRemoved(1, 7));
+        assert_eq!(Bank::spend_details(1, 1, 7), None);
+    });
+}
+
+#[test]
+fn max_automated_spends_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        for _ in 0..2 {
+            assert_ok!(Bank::member_proposes_conditional_spend(
+                Origin::signed(2),
+                1,
+                10,
+                7,
+                SpendCondition::AfterBlock(100)
+            ));
+        }
+        assert_eq!(Bank::automated_spend_count(1), 2);
+        assert_noop!(
+            Bank::member_proposes_conditional_spend(
+                Origin::signed(2),
+                1,
+                10,
+                7,
+                SpendCondition::AfterBlock(100)
+            ),
+            Error::<Test>::TooManyAutomatedSpends
+        );
+        // plain spends are not automated so they are not capped
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        // a rejected conditional spend frees its slot
+        reject_spend(1, 1, 8);
+        assert_eq!(Bank::automated_spend_count(1), 1);
+        assert!(Bank::spend_conditions(1, 1).is_none());
+        assert_ok!(Bank::member_proposes_conditional_spend(
+            Origin::signed(2),
+            1,
+            10,
+            7,
+            SpendCondition::AfterBlock(100)
+        ));
+        assert_eq!(Bank::automated_spend_count(1), 2);
+    });
+}
+
+#[test]
+fn tribute_match_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::set_tribute_match(Origin::signed(2), 1, Permill::one()),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        // 50% of 4 requested shares
+        assert_ok!(Bank::set_tribute_match(
+            Origin::signed(1),
+            1,
+            Permill::from_percent(50)
+        ));
+        assert_ok!(sponsor_applicant(1, 1, 10, 4, 7));
+        approve_member(1, 1, 8);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::TributeMatched(1, 1, 7, 2))
+        }));
+        assert_eq!(Org::outstanding_shares(1), 12);
+        // 100% of 3 requested shares is clamped to the 1 share left under the cap
+        assert_ok!(Bank::set_tribute_match(
+            Origin::signed(1),
+            1,
+            Permill::one()
+        ));
+        assert_ok!(Bank::set_max_total_shares(Origin::signed(1), 1, Some(16)));
+        assert_ok!(Bank::propose_member(
+            Origin::signed(1),
+            1,
+            0,
+            3,
+            0,
+            8,
+            None,
+            None
+        ));
+        approve_member(2, 2, 15);
+        assert_eq!(
+            Bank::member_props(1, 2).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::TributeMatched(1, 2, 8, 1))
+        }));
+        assert_eq!(Org::outstanding_shares(1), 16);
+    });
+}
+
+#[test]
+fn dissolution_proceeds_escrow_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(vec![(1, 1), (8, 1)]),
+            None,
+            10
+        ));
+        let threshold = ThresholdInput::new(
+            OrgRep::Weighted(2),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            28,
+            Some(1),
+            threshold,
+            None
+        ));
+        // 8 has no account so its half of the 8 distributed is below the
+        // existential deposit and cannot be transferred
+        ExistentialDeposit::set(20);
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            false,
+            20,
+            20
+        ));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::DissolutionProceedsEscrowed(
+                    1, 8, 4,
+                ))
+        }));
+        assert_eq!(Bank::unclaimed_dissolution(1, 8), 4);
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 20);
+        assert_eq!(Balances::total_balance(&1), 80);
+        assert_noop!(
+            Bank::claim_dissolution_proceeds(Origin::signed(1), 1),
+            Error::<Test>::NoDissolutionProceedsToClaim
+        );
+        Balances::make_free_balance_be(&8, 20);
+        assert_ok!(Bank::claim_dissolution_proceeds(Origin::signed(8), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DissolutionProceedsClaimed(1, 8, 4)
+        );
+        assert_eq!(Balances::total_balance(&8), 24);
+        assert_eq!(Bank::unclaimed_dissolution(1, 8), 0);
+    });
+}
+
+#[test]
+fn member_ragequit_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(vec![(1, 4), (2, 2), (3, 2)]),
+            None,
+            10
+        ));
+        let threshold = ThresholdInput::new(
+            OrgRep::Weighted(2),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            40,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(2), 1, 0, 20),
+            Error::<Test>::InsufficientSharesToRagequit
+        );
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(2), 1, 3, 20),
+            Error::<Test>::InsufficientSharesToRagequit
+        );
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(7), 1, 1, 20),
+            Error::<Test>::InsufficientSharesToRagequit
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(3), 1, 1, 20),
+            Error::<Test>::CannotRagequitWithVoteInFlight
+        );
+        // 2 has not voted so may leave with 1/8 of the 30 not reserved for it
+        assert_ok!(Bank::member_ragequit(Origin::signed(2), 1, 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(2, 1, 1, 3, 0));
+        assert_eq!(Balances::total_balance(&2), 101);
+        assert_eq!(Org::outstanding_shares(2), 7);
+        // once the vote resolves 3 may leave with 1/7 of the remaining 27
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(1),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_ok!(Bank::member_ragequit(Origin::signed(3), 1, 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(3, 1, 1, 3, 0));
+    });
+}
+
+#[test]
+fn member_cancels_spend_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::member_cancels_spend(Origin::signed(2), 1, 1),
+            Error::<Test>::CannotCancelSpendProposalThatDNE
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_eq!(Balances::reserved_balance(&2), 4);
+        assert_noop!(
+            Bank::member_cancels_spend(Origin::signed(1), 1, 1),
+            Error::<Test>::NotPermittedToCancelSpendProposal
+        );
+        assert_ok!(Bank::member_cancels_spend(Origin::signed(2), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::SpendProposalCancelled(2, 1, 1));
+        assert!(Bank::spend_props(1, 1).is_none());
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_noop!(
+            Bank::member_cancels_spend(Origin::signed(2), 1, 2),
+            Error::<Test>::CannotCancelSpendProposalOnceVoteTriggered
+        );
+    });
+}
+
+#[test]
+fn member_retries_spend_execution_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 30, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 25, 6, None));
+        assert_noop!(
+            Bank::member_retries_spend_execution(Origin::signed(2), 1, 1),
+            Error::<Test>::CannotRetrySpendExecutionIfNotApprovedButNotExecuted
+        );
+        // the other spend leaves the bank unable to cover this one
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_noop!(
+            Bank::member_retries_spend_execution(Origin::signed(7), 1, 1),
+            Error::<Test>::MustBeMemberToRetrySpendExecution
+        );
+        assert_ok!(Bank::member_retries_spend_execution(
+            Origin::signed(2),
+            1,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendExecutionRetried(1, 1, false)
+        );
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        // a later deposit makes the spend executable
+        assert_ok!(Balances::transfer(
+            Origin::signed(3),
+            Bank::bank_account_id(1),
+            20
+        ));
+        assert_ok!(Bank::member_retries_spend_execution(
+            Origin::signed(2),
+            1,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendExecutionRetried(1, 1, true)
+        );
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 107);
+    });
+}
+
+#[test]
+fn bank_thresholds_work() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        let unanimous = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::set_bank_thresholds(
+                Origin::signed(2),
+                1,
+                Some(unanimous.clone()),
+                None
+            ),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        let unsupported = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::zero(), None)),
+        );
+        assert_noop!(
+            Bank::set_bank_thresholds(
+                Origin::signed(1),
+                1,
+                None,
+                Some(unsupported)
+            ),
+            Error::<Test>::ThresholdMustRequireSupport
+        );
+        let other_org = ThresholdInput::new(
+            OrgRep::Equal(2),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::set_bank_thresholds(
+                Origin::signed(1),
+                1,
+                Some(other_org),
+                None
+            ),
+            Error::<Test>::ThresholdCannotBeSetForOrg
+        );
+        // spends need every member while membership keeps the bank's majority
+        assert_ok!(Bank::set_bank_thresholds(
+            Origin::signed(1),
+            1,
+            Some(unanimous),
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankThresholdsSet(1, Bank::spend_threshold(1), None)
+        );
+        assert!(Bank::spend_threshold(1).is_some());
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..6u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        approve_member(1, 2, 8);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::Voting(1)
+        );
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        // clearing the override restores the bank's threshold
+        assert_ok!(Bank::set_bank_thresholds(Origin::signed(1), 1, None, None));
+        assert!(Bank::spend_threshold(1).is_none());
+    });
+}
+
+#[test]
+fn member_funds_bank_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::member_funds_bank(Origin::signed(2), 1, 20),
+            Error::<Test>::CannotFundBankThatDNE
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::member_funds_bank(Origin::signed(7), 1, 20),
+            Error::<Test>::MustBeMemberToFundBank
+        );
+        assert_ok!(Bank::member_funds_bank(Origin::signed(2), 1, 20));
+        assert_eq!(get_last_event(), RawEvent::BankFunded(2, 1, 20));
+        assert_eq!(Bank::bank_balance(1), 70);
+        assert_eq!(Balances::total_balance(&2), 78);
+        // the member cannot empty their own account to fund the bank
+        assert_noop!(
+            Bank::member_funds_bank(Origin::signed(5), 1, 10),
+            pallet_balances::Error::<Test, _>::KeepAlive
+        );
+    });
+}
+
+#[test]
+fn transfer_bank_control_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::transfer_bank_control(Origin::signed(2), 1, Some(2)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::transfer_bank_control(Origin::signed(1), 1, Some(2)));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankControlTransferred(1, Some(1), Some(2))
+        );
+        assert_eq!(Bank::bank_stores(1).unwrap().controller(), Some(2));
+        assert_ok!(Bank::propose_spend(Origin::signed(3), 1, 10, 7, None));
+        assert_noop!(
+            Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1),
+            Error::<Test>::NotPermittedToSudoApproveForBankAccount
+        );
+        // relinquishing control leaves the bank fully member-governed
+        assert_ok!(Bank::transfer_bank_control(Origin::signed(2), 1, None));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::BankControlTransferred(1, Some(2), None)
+        );
+        assert_eq!(Bank::bank_stores(1).unwrap().controller(), None);
+        assert_noop!(
+            Bank::sudo_approve_spend_proposal(Origin::signed(2), 1, 1),
+            Error::<Test>::NotPermittedToSudoApproveForBankAccount
+        );
+        assert_noop!(
+            Bank::transfer_bank_control(Origin::signed(2), 1, Some(2)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+    });
+}
+
+#[test]
+fn guild_kick_converts_shares_to_loot() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::propose_guild_kick(Origin::signed(7), 1, 6),
+            Error::<Test>::MustBeMemberToSponsorProposal
+        );
+        assert_noop!(
+            Bank::propose_guild_kick(Origin::signed(2), 1, 7),
+            Error::<Test>::CannotKickAccountThatIsNotMember
+        );
+        assert_ok!(Bank::propose_guild_kick(Origin::signed(2), 1, 6));
+        assert_eq!(get_last_event(), RawEvent::GuildKickProposed(2, 1, 1, 6));
+        // kicks share the id space of membership proposals
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert!(Bank::member_props(1, 2).is_some());
+        assert_ok!(Bank::kick_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::KickVoteTriggered(1, 1, 1, 1));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(8);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::GuildKickExecuted(1, 1, 6, 1))
+        }));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::KickProposalPolled(
+                    1,
+                    1,
+                    ProposalState::ApprovedAndExecuted,
+                    Some((4, 0)),
+                    ResolutionTrigger::Auto,
+                ))
+        }));
+        assert_eq!(
+            Bank::kick_props(1, 1).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert_noop!(
+            Bank::kick_trigger_vote(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotTriggerVoteFromCurrentKickProposalState
+        );
+        // the kicked member no longer votes but may claim their loot
+        assert!(!Org::is_member_of_group(1, &6));
+        assert_eq!(Org::outstanding_shares(1), 5);
+        assert_eq!(Bank::loot(1, 6), 1);
+        assert_eq!(Bank::total_loot(1), 1);
+        assert_noop!(
+            Bank::member_trigger_vote(Origin::signed(6), 1, 2),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+        // 1 loot of a 5 share + 1 loot base claims 1/6 of 50
+        assert_ok!(Bank::claim_loot(Origin::signed(6), 1, 1));
+        assert_eq!(get_last_event(), RawEvent::LootClaimed(1, 6, 1, 8));
+        assert_eq!(Balances::total_balance(&6), 77);
+    });
+}
+
+#[test]
+fn guild_kicks_share_the_proposal_guards() {
+    new_test_ext().execute_with(|| {
+        MaxOpenProposalsPerMember::set(1);
+        ShareVestingPeriod::set(10);
+        summon_default_bank();
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(1, 1, 8);
+        // kicks count against the proposer's open proposal cap
+        assert_ok!(Bank::propose_guild_kick(Origin::signed(2), 1, 6));
+        assert_eq!(Bank::open_proposal_count(1, 2), 1);
+        assert_eq!(Bank::kick_expiry(1, 2), Some(108));
+        assert_noop!(
+            Bank::propose_guild_kick(Origin::signed(2), 1, 5),
+            Error::<Test>::TooManyOpenProposals
+        );
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 10, 7, None),
+            Error::<Test>::TooManyOpenProposals
+        );
+        // unvested shares cannot trigger a kick vote
+        assert_noop!(
+            Bank::kick_trigger_vote(Origin::signed(7), 1, 2),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+        // nor may anyone while the bank is frozen
+        assert_ok!(Bank::freeze_bank(Origin::signed(1), 1));
+        assert_noop!(
+            Bank::kick_trigger_vote(Origin::signed(1), 1, 2),
+            Error::<Test>::BankFrozen
+        );
+        assert_ok!(Bank::unfreeze_bank(Origin::signed(1), 1));
+        assert_ok!(Bank::kick_trigger_vote(Origin::signed(1), 1, 2));
+        // a resolved kick frees its proposer's slot
+        MockVoteMachine::force_outcome(VoteOutcome::Rejected);
+        assert_ok!(Bank::poke_concluded(Origin::signed(6), 5));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::KickProposalPolled(
+                    1,
+                    2,
+                    ProposalState::Rejected(2),
+                    Some((0, 0)),
+                    ResolutionTrigger::Member(6),
+                ))
+        }));
+        assert_eq!(
+            Bank::kick_props(1, 2).unwrap().state(),
+            ProposalState::Rejected(2)
+        );
+        assert_eq!(Bank::open_proposal_count(1, 2), 0);
+        assert_ok!(Bank::propose_guild_kick(Origin::signed(2), 1, 5));
+    });
+}
+
+#[test]
+fn stale_proposals_expire() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_ok!(Bank::propose_guild_kick(Origin::signed(3), 1, 6));
+        assert_eq!(Bank::spend_expiry(1, 1), Some(101));
+        assert_eq!(Bank::member_expiry(1, 1), Some(101));
+        assert_eq!(Bank::kick_expiry(1, 2), Some(101));
+        assert_eq!(Balances::reserved_balance(&2), 4);
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        run_to_block(99);
+        assert!(Bank::spend_props(1, 1).is_some());
+        assert!(Bank::member_props(1, 1).is_some());
+        run_to_block(106);
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::SpendProposalExpired(1, 1))
+        }));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::MemberProposalExpired(1, 1))
+        }));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::KickProposalExpired(1, 2))
+        }));
+        assert!(Bank::kick_props(1, 2).is_none());
+        assert!(Bank::kick_expiry(1, 2).is_none());
+        assert_eq!(Bank::open_proposal_count(1, 3), 0);
+        assert!(Bank::spend_props(1, 1).is_none());
+        assert!(Bank::spend_expiry(1, 1).is_none());
+        assert!(Bank::member_props(1, 1).is_none());
+        assert_eq!(Balances::reserved_balance(&2), 2);
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        // the spend in a live vote is exempt
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::Voting(1)
+        );
+    });
+}
+
+#[test]
+fn expired_membership_tribute_is_refunded() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        run_to_block(106);
+        assert!(Bank::member_props(1, 1).is_none());
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::ExpiredMembershipTributeRefunded(
+                    1, 1, 7, 10,
+                ))
+        }));
+        assert!(!System::events().into_iter().any(|r| {
+            matches!(r.event, TestEvent::bank(RawEvent::TributeRefunded(..)))
+        }));
+        assert_eq!(Balances::reserved_balance(&7), 0);
+        assert_eq!(Balances::free_balance(&7), 77);
+    });
+}
+
+#[test]
+fn only_active_proposals_are_polled() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        assert_eq!(Bank::active_spend_props(1), vec![1, 2]);
+        assert_eq!(Bank::active_member_props(1), vec![1]);
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        approve_member(1, 1, 8);
+        // resolved proposals leave the active sets once polled
+        assert_eq!(Bank::active_spend_props(1), vec![2]);
+        assert!(Bank::active_member_props(1).is_empty());
+        run_to_block(15);
+        let polls_first_spend = |e: &TestEvent| {
+            matches!(
+                e,
+                TestEvent::bank(RawEvent::SpendProposalPolled(
+                    1,
+                    1,
+                    _,
+                    _,
+                    ResolutionTrigger::Auto
+                ))
+            )
+        };
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .filter(|r| polls_first_spend(&r.event))
+                .count(),
+            1
+        );
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendProposalPolled(
+                    1,
+                    2,
+                    SpendState::WaitingForApproval,
+                    None,
+                    ResolutionTrigger::Auto,
+                ))
+        }));
+    });
+}
+
+#[test]
+fn banks_are_polled_from_their_due_block_schedule() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_eq!(Bank::spend_polls_due(7), vec![1]);
+        assert_eq!(Bank::member_polls_due(7), vec![1]);
+        assert_ok!(Bank::propose_spend(Origin::signed(3), 1, 10, 7, None));
+        run_to_block(7);
+        // the bank is rescheduled one period on
+        assert!(Bank::spend_polls_due(7).is_empty());
+        assert_eq!(Bank::spend_polls_due(14), vec![1]);
+        // a new cadence joins the existing schedule without duplicating it
+        assert_ok!(Bank::set_poll_frequency(Origin::signed(1), 1, 2, 7));
+        assert_eq!(Bank::spend_polls_due(8), vec![1]);
+        run_to_block(8);
+        assert_eq!(Bank::spend_polls_due(10), vec![1]);
+        run_to_block(14);
+        assert_eq!(Bank::spend_polls_due(16), vec![1]);
+        assert!(Bank::spend_poll_queue().is_empty());
+    });
+}
+
+#[test]
+fn bank_size_witnesses_must_cover_the_bank() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        // two spend ids issued and six holders
+        assert_noop!(
+            Bank::close_org_bank_account(Origin::signed(1), 1, true, 6, 1),
+            Error::<Test>::BankSizeWitnessTooLow
+        );
+        assert_noop!(
+            Bank::close_org_bank_account(Origin::signed(1), 1, true, 5, 2),
+            Error::<Test>::BankSizeWitnessTooLow
+        );
+        // two active spends
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(2), 1, 1, 1),
+            Error::<Test>::BankSizeWitnessTooLow
+        );
+        assert_ok!(Bank::member_ragequit(Origin::signed(2), 1, 1, 2));
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            true,
+            6,
+            2
+        ));
+    });
+}
+
+#[test]
+fn polling_backlog_drains_across_blocks() {
+    new_test_ext().execute_with(|| {
+        MaxProposalsPolledPerBlock::set(2);
+        summon_default_bank();
+        for _ in 0..3 {
+            assert_ok!(Bank::propose_spend(Origin::signed(3), 1, 10, 7, None));
+        }
+        let polled = || {
+            System::events()
+                .into_iter()
+                .filter(|r| {
+                    matches!(
+                        r.event,
+                        TestEvent::bank(RawEvent::SpendProposalPolled(
+                            1,
+                            _,
+                            _,
+                            _,
+                            _
+                        ))
+                    )
+                })
+                .count()
+        };
+        run_to_block(7);
+        assert_eq!(polled(), 2);
+        assert_eq!(Bank::spend_poll_queue().len(), 1);
+        run_to_block(8);
+        assert_eq!(polled(), 3);
+        assert!(Bank::spend_poll_queue().is_empty());
+        // nothing is polled again until the next pass
+        run_to_block(13);
+        assert_eq!(polled(), 3);
+        run_to_block(14);
+        assert_eq!(polled(), 5);
+    });
+}
+
+#[test]
+fn rejected_votes_are_distinguishable_from_ongoing() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..4u64 {
+            for vote_id in 1u64..3u64 {
+                assert_ok!(Vote::submit_vote(
+                    Origin::signed(i),
+                    vote_id,
+                    VoterView::Against,
+                    None
+                ));
+            }
+        }
+        run_to_block(8);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendProposalPolled(
+                    1,
+                    1,
+                    SpendState::Rejected(1),
+                    Some((0, 3)),
+                    ResolutionTrigger::Auto,
+                ))
+        }));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::MemberProposalPolled(
+                    1,
+                    1,
+                    ProposalState::Rejected(2),
+                    Some((0, 3)),
+                    ResolutionTrigger::Auto,
+                ))
+        }));
+        // the final tallies stay on record after the votes conclude
+        assert_eq!(Bank::spend_proposal_tally(1, 1), Some((0, 3)));
+        assert_eq!(Bank::member_proposal_tally(1, 1), Some((0, 3)));
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotTriggerVoteFromCurrentSpendProposalState
+        );
+        assert_noop!(
+            Bank::member_trigger_vote(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotTriggerVoteFromCurrentMemberProposalState
+        );
+    });
+}
+
+#[test]
+fn proposal_listing_pages_by_cursor() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        for amount in 5..10 {
+            assert_ok!(Bank::propose_spend(
+                Origin::signed(2),
+                1,
+                amount,
+                7,
+                None
+            ));
+        }
+        let ids = |page: Vec<(u64, SpendProp<Test>)>| {
+            page.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+        };
+        let (page, next) = Bank::spend_proposals_for_bank(1, false, None, 1);
+        assert_eq!(ids(page), vec![1]);
+        assert_eq!(next, Some(1));
+        // the limit is capped by the max page size
+        let (page, next) = Bank::spend_proposals_for_bank(1, false, next, 10);
+        assert_eq!(ids(page), vec![2, 3]);
+        assert_eq!(next, Some(3));
+        let (page, next) = Bank::spend_proposals_for_bank(1, false, next, 10);
+        assert_eq!(ids(page), vec![4, 5]);
+        assert_eq!(next, None);
+        let (page, next) =
+            Bank::spend_proposals_for_bank(1, false, Some(5), 10);
+        assert!(page.is_empty());
+        assert_eq!(next, None);
+    });
+}
+
+#[test]
+fn proposals_for_bank_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 2, 5, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        let (spends, next) = Bank::spend_proposals_for_bank(1, false, None, 10);
+        let spend_ids =
+            spends.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+        assert_eq!(spend_ids, vec![1, 2]);
+        assert_eq!(next, None);
+        let (voting, _) = Bank::spend_proposals_for_bank(1, true, None, 10);
+        assert_eq!(voting.len(), 1);
+        assert_eq!(voting[0].0, 2);
+        assert_eq!(voting[0].1.state(), SpendState::Voting(1));
+        let (members, _) = Bank::member_proposals_for_bank(1, false, None, 10);
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].1.applicant(), 7);
+        assert!(Bank::member_proposals_for_bank(1, true, None, 10)
+            .0
+            .is_empty());
+        assert!(Bank::member_proposals_for_bank(2, false, None, 10)
+            .0
+            .is_empty());
+    });
+}
+
+#[test]
+fn per_bank_poll_frequency_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::set_poll_frequency(Origin::signed(2), 1, 3, 3),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_noop!(
+            Bank::set_poll_frequency(Origin::signed(1), 1, 0, 3),
+            Error::<Test>::PollFrequencyMustBeNonZero
+        );
+        assert_ok!(Bank::set_poll_frequency(Origin::signed(1), 1, 3, 3));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::PollFrequencySet(1, 3, 3)
+        );
+        assert_eq!(Bank::spend_poll_frequency_of(1), 3);
+        assert_eq!(Bank::spend_poll_frequency_of(2), 7);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 2, 5, 7, None));
+        let polled = |bank_id: u64| {
+            System::events()
+                .into_iter()
+                .filter(|r| {
+                    matches!(
+                        r.event,
+                        TestEvent::bank(RawEvent::SpendProposalPolled(b, _, _, _, _)) if b == bank_id
+                    )
+                })
+                .count()
+        };
+        // the overridden bank is polled on its own cadence
+        run_to_block(3);
+        assert_eq!(polled(1), 1);
+        assert_eq!(polled(2), 0);
+        run_to_block(7);
+        assert_eq!(polled(1), 2);
+        assert_eq!(polled(2), 1);
+    });
+}
+
+#[test]
+fn genesis_seeds_banks_and_members() {
+    ExtBuilder::default()
+        .bank((1, 1, 30, Some(1), Permill::from_percent(51)))
+        .member((1, 7, 2))
+        .member((1, 2, 3))
+        .spend((1, 7, 5, 3))
+        .build()
+        .execute_with(|| {
+            let bank = Bank::bank_stores(1).unwrap();
+            assert_eq!(bank.org(), 1);
+            assert_eq!(bank.controller(), Some(1));
+            assert_eq!(Bank::bank_balance(1), 30);
+            assert_eq!(Balances::free_balance(&1), 70);
+            assert_eq!(Bank::org_bank_count(1), 1);
+            assert_eq!(Bank::total_bank_count(), 1);
+            assert_eq!(Org::get_share_profile(1, &7).unwrap().total(), 2);
+            assert_eq!(Org::get_share_profile(1, &2).unwrap().total(), 4);
+            assert_eq!(Org::outstanding_shares(1), 11);
+            let spend = Bank::spend_props(1, 1).unwrap();
+            assert_eq!(spend.state(), SpendState::WaitingForApproval);
+            assert_eq!(spend.proposer(), 7);
+            assert_eq!(Balances::reserved_balance(&7), 2);
+        });
+}
+
+#[test]
+fn ext_builder_overrides_mock_defaults() {
+    ExtBuilder::default()
+        .balances(vec![(1, 500), (7, 1)])
+        .min_deposit(100)
+        .spend_poll_frequency(3)
+        .build()
+        .execute_with(|| {
+            assert_eq!(Bank::spend_poll_frequency(), 3);
+            assert_eq!(Bank::member_poll_frequency(), 7);
+            let threshold = ThresholdInput::new(
+                OrgRep::Equal(1),
+                XorThreshold::Percent(Threshold::new(
+                    Permill::from_percent(50),
+                    None,
+                )),
+            );
+            assert_noop!(
+                Bank::summon(
+                    Origin::signed(1),
+                    1,
+                    99,
+                    None,
+                    threshold.clone(),
+                    None
+                ),
+                Error::<Test>::CannotOpenBankAccountIfDepositIsBelowModuleMinimum
+            );
+            assert_ok!(Bank::summon(
+                Origin::signed(1),
+                1,
+                100,
+                None,
+                threshold,
+                None
+            ));
+            assert_eq!(Balances::total_balance(&1), 400);
+        });
+}
+
+#[test]
+#[should_panic(expected = "genesis members must join a genesis bank")]
+fn genesis_rejects_members_of_unknown_bank() {
+    ExtBuilder::default().member((1, 7, 2)).build();
+}
+
+#[test]
+#[should_panic(expected = "poll frequencies must be non-zero")]
+fn genesis_rejects_zero_poll_frequency() {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    GenesisConfig::<Test> {
+        spend_poll_frequency: 0,
+        member_poll_frequency: 7,
+        banks: Vec::new(),
+        initial_members: Vec::new(),
+        spends: Vec::new(),
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+}
+
+#[test]
+fn zero_poll_frequency_skips_polling() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(sponsor_applicant(1, 1, 10, 1, 7));
+        SpendPollFrequency::<Test>::put(0);
+        MemberPollFrequency::<Test>::put(0);
+        // blocks keep finalizing, nothing is polled
+        run_to_block(15);
+        assert!(!System::events().into_iter().any(|r| {
+            matches!(
+                r.event,
+                TestEvent::bank(RawEvent::SpendProposalPolled(..))
+                    | TestEvent::bank(RawEvent::MemberProposalPolled(..))
+            )
+        }));
+    });
+}
+
+#[test]
+fn split_spend_pays_every_leg() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::member_proposes_split_spend(Origin::signed(2), 1, vec![]),
+            Error::<Test>::SplitSpendMustHavePayouts
+        );
+        assert_noop!(
+            Bank::member_proposes_split_spend(
+                Origin::signed(2),
+                1,
+                vec![(7, 1), (6, 1), (5, 1), (4, 1)]
+            ),
+            Error::<Test>::TooManySplitLegs
+        );
+        assert_ok!(Bank::member_proposes_split_spend(
+            Origin::signed(2),
+            1,
+            vec![(7, 10), (6, 5), (8, 15)]
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SplitSpendProposed(2, 1, 1, 30, 3)
+        );
+        assert_eq!(Bank::spend_props(1, 1).unwrap().amount(), 30);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 25, 7, None));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        // the last leg overdraws the bank so only it is left outstanding
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::SplitSpendExecuted(1, 1, 2))
+        }));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_eq!(Bank::split_payouts(1, 1), Some(vec![(8, 15)]));
+        assert_eq!(Balances::total_balance(&7), 112);
+        assert_eq!(Balances::total_balance(&6), 74);
+        // retrying once the bank is funded pays only the failed leg
+        assert_ok!(Bank::member_funds_bank(Origin::signed(3), 1, 20));
+        assert_ok!(Bank::member_retries_spend_execution(
+            Origin::signed(2),
+            1,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendExecutionRetried(1, 1, true)
+        );
+        assert_eq!(Bank::split_payouts(1, 1), None);
+        assert_eq!(Balances::total_balance(&7), 112);
+        assert_eq!(Balances::total_balance(&8), 15);
+        assert_eq!(Bank::bank_balance(1), 15);
+    });
+}
+
+#[test]
+fn proposals_carry_metadata_hash() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        let doc = H256::repeat_byte(7);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, Some(doc)));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendProposed(2, 1, 1, 10, 7, 0, Some(doc))
+        );
+        assert_eq!(Bank::spend_metadata(1, 1), Some(doc));
+        assert_ok!(Bank::propose_member(
+            Origin::signed(2),
+            1,
+            0,
+            1,
+            0,
+            7,
+            Some(doc),
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MemberProposed(2, 1, 1, 0, 1, 0, 7, 0, Some(doc))
+        );
+        assert_eq!(Bank::member_metadata(1, 1), Some(doc));
+        // cancelling the spend drops its metadata
+        assert_ok!(Bank::member_cancels_spend(Origin::signed(2), 1, 1));
+        assert_eq!(Bank::spend_metadata(1, 1), None);
+    });
+}
+
+#[test]
+fn spends_below_minimum_are_rejected() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 0, 7, None),
+            Error::<Test>::SpendBelowMinimum
+        );
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 4, 7, None),
+            Error::<Test>::SpendBelowMinimum
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 7, None));
+    });
+}
+
+#[test]
+fn spends_above_available_balance_are_rejected() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        // 30 is available above the minimum deposit of 20
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 31, 7, None),
+            Error::<Test>::SpendExceedsBankBalance
+        );
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 29, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 30, 7, None));
+    });
+}
+
+#[test]
+fn spend_funds_are_reserved_while_voting() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                Some(Permill::from_percent(50)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        let bank_account = Bank::bank_account_id(1);
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 30, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 25, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_eq!(Balances::reserved_balance(&bank_account), 30);
+        assert_eq!(Bank::spend_reservations(1, 1), Some(30));
+        // only 20 is left unreserved
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(1), 1, 2),
+            Error::<Test>::BankFundsAlreadyReservedByLiveSpends
+        );
+        // rejection releases the reservation
+        for i in 1u64..4u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+        }
+        run_to_block(7);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::Rejected(1)
+        );
+        assert_eq!(Balances::reserved_balance(&bank_account), 0);
+        // approval releases the reservation before transferring
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_eq!(Balances::reserved_balance(&bank_account), 25);
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        assert_eq!(Balances::reserved_balance(&bank_account), 0);
+        assert_eq!(Bank::spend_reservations(1, 2), None);
+        assert_eq!(Balances::total_balance(&7), 102);
+        assert_eq!(Bank::bank_balance(1), 25);
+    });
+}
+
+#[test]
+fn frozen_bank_halts_spends() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(3),
+            threshold,
+            None
+        ));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(2), 1, 1));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 6, None));
+        assert_noop!(
+            Bank::freeze_bank(Origin::signed(2), 1),
+            Error::<Test>::NotPermittedToFreezeBank
+        );
+        // the org supervisor may freeze as well as the controller
+        assert_ok!(Bank::freeze_bank(Origin::signed(1), 1));
+        assert_eq!(get_last_event(), RawEvent::BankFrozen(1, 1));
+        assert_noop!(
+            Bank::propose_spend(Origin::signed(2), 1, 10, 5, None),
+            Error::<Test>::BankFrozen
+        );
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(2), 1, 2),
+            Error::<Test>::BankFrozen
+        );
+        assert_noop!(
+            Bank::sudo_approve_spend_proposal(Origin::signed(3), 1, 2),
+            Error::<Test>::BankFrozen
+        );
+        // votes already underway still resolve
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        run_to_block(7);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_ok!(Bank::unfreeze_bank(Origin::signed(3), 1));
+        assert_eq!(get_last_event(), RawEvent::BankUnfrozen(1, 3));
+        assert!(!Bank::frozen_banks(1));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(2), 1, 2));
+    });
+}
+
+#[test]
+fn close_distribution_follows_share_ownership() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(vec![(1, 1), (2, 1), (3, 1)]),
+            None,
+            10
+        ));
+        assert_ok!(Org::register_organization(
+            OrganizationSource::AccountsWeighted(vec![(4, 1), (5, 2), (6, 3)]),
+            None,
+            10
+        ));
+        let threshold = |org| {
+            ThresholdInput::new(
+                OrgRep::Weighted(org),
+                XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+            )
+        };
+        // 30 above the existential deposit splits into exact thirds
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            31,
+            Some(1),
+            threshold(2),
+            None
+        ));
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            false,
+            20,
+            20
+        ));
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 0);
+        // 10 each, with the remaining 1 swept to the closer
+        assert_eq!(Balances::total_balance(&1), 80);
+        assert_eq!(Balances::total_balance(&2), 108);
+        assert_eq!(Balances::total_balance(&3), 210);
+        // 43 split 1:2:3 rounds down to 7, 14 and 21, leaving 2 for the closer
+        assert_ok!(Bank::summon(
+            Origin::signed(4),
+            3,
+            44,
+            Some(4),
+            threshold(3),
+            None
+        ));
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(4),
+            2,
+            false,
+            20,
+            20
+        ));
+        let swept = TestEvent::bank(RawEvent::CloseDustSwept(
+            2,
+            2,
+            CloseDustDestination::Closer,
+        ));
+        assert!(System::events().into_iter().any(|r| r.event == swept));
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(2)), 0);
+        assert_eq!(Balances::total_balance(&4), 40);
+        assert_eq!(Balances::total_balance(&5), 24);
+        assert_eq!(Balances::total_balance(&6), 90);
+    });
+}
+
+#[test]
+fn members_are_rewarded_for_processing_concluded_votes() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 6, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_noop!(
+            Bank::member_processes_spend(Origin::signed(7), 1, 1),
+            Error::<Test>::MustBeMemberToProcessProposal
+        );
+        assert_noop!(
+            Bank::member_processes_spend(Origin::signed(3), 1, 2),
+            Error::<Test>::CannotProcessProposalWithoutLiveVote
+        );
+        assert_noop!(
+            Bank::member_processes_spend(Origin::signed(3), 1, 1),
+            Error::<Test>::CannotProcessProposalBeforeVoteConcludes
+        );
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // resolved ahead of the next poll
+        assert_ok!(Bank::member_processes_spend(Origin::signed(3), 1, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SpendProcessedForReward(3, 1, 1, 1)
+        );
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert_eq!(Balances::total_balance(&3), 201);
+        assert_eq!(Bank::bank_balance(1), 39);
+        assert_eq!(Bank::active_spend_props(1), vec![2]);
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_ok!(Bank::member_processes_member_proposal(
+            Origin::signed(4),
+            1,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MemberProposalProcessedForReward(4, 1, 1, 1)
+        );
+        assert_eq!(Balances::total_balance(&4), 76);
+        assert!(Bank::active_member_props(1).is_empty());
+    });
+}
+
+#[test]
+fn loot_shares_in_ragequit_and_close() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(3),
+            1,
+            60,
+            Some(1),
+            threshold,
+            None
+        ));
+        // 7 pays 10 into the bank for 4 loot and no voting shares
+        assert_ok!(Balances::transfer(
+            Origin::signed(7),
+            Bank::bank_account_id(1),
+            10
+        ));
+        assert_ok!(Bank::propose_member(
+            Origin::signed(2),
+            1,
+            0,
+            0,
+            4,
+            7,
+            None,
+            None
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MemberProposed(2, 1, 1, 0, 0, 4, 7, 0, None)
+        );
+        approve_member(1, 1, 8);
+        assert!(!Org::is_member_of_group(1, &7));
+        // 1 share of a 6 share + 4 loot base leaves with 1/10 of 70
+        assert_ok!(Bank::member_ragequit(Origin::signed(2), 1, 1, 20));
+        assert_eq!(get_last_event(), RawEvent::MemberRagequit(2, 1, 1, 7, 0));
+        assert_eq!(Balances::total_balance(&2), 105);
+        // 62 above the existential deposit splits over 5 shares + 4 loot
+        assert_ok!(Bank::close_org_bank_account(
+            Origin::signed(1),
+            1,
+            false,
+            20,
+            20
+        ));
+        assert_eq!(Balances::total_balance(&Bank::bank_account_id(1)), 0);
+        assert_eq!(Balances::total_balance(&7), 94);
+        assert_eq!(Balances::total_balance(&3), 146);
+        // 6 to the closer as a member and the remaining 6 swept to it
+        assert_eq!(Balances::total_balance(&1), 112);
+        assert_eq!(Bank::loot(1, 7), 0);
+        assert_eq!(Bank::total_loot(1), 0);
+    });
+}
+
+#[test]
+fn applicant_requests_then_member_sponsors() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            30,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_noop!(
+            Bank::applicant_submits_membership_request(
+                Origin::signed(8),
+                1,
+                5,
+                1
+            ),
+            Error::<Test>::InsufficientBalanceToReserveTribute
+        );
+        // the applicant escrows its own tribute
+        assert_ok!(Bank::applicant_submits_membership_request(
+            Origin::signed(7),
+            1,
+            10,
+            1
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipRequested(7, 1, 1, 10, 1)
+        );
+        assert_eq!(Balances::reserved_balance(&7), 10);
+        assert_noop!(
+            Bank::member_sponsors_request(Origin::signed(8), 1, 1),
+            Error::<Test>::MustBeMemberToSponsorProposal
+        );
+        assert_noop!(
+            Bank::member_sponsors_request(Origin::signed(2), 1, 2),
+            Error::<Test>::CannotSponsorRequestThatDNE
+        );
+        // a request whose escrow was released elsewhere cannot be sponsored
+        assert_ok!(Bank::applicant_submits_membership_request(
+            Origin::signed(6),
+            1,
+            5,
+            1
+        ));
+        Balances::unreserve(&6, 5);
+        assert_noop!(
+            Bank::member_sponsors_request(Origin::signed(2), 1, 2),
+            Error::<Test>::ApplicantLacksEscrowedTribute
+        );
+        assert_noop!(
+            Bank::applicant_withdraws_request(Origin::signed(7), 1, 2),
+            Error::<Test>::NotPermittedToWithdrawRequest
+        );
+        assert_ok!(Bank::applicant_withdraws_request(Origin::signed(6), 1, 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipRequestWithdrawn(6, 1, 2, 5)
+        );
+        assert_noop!(
+            Bank::applicant_withdraws_request(Origin::signed(6), 1, 2),
+            Error::<Test>::CannotWithdrawRequestThatDNE
+        );
+        // sponsoring moves the request into the votable proposal set
+        assert_ok!(Bank::member_sponsors_request(Origin::signed(2), 1, 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipRequestSponsored(2, 1, 1, 1)
+        );
+        assert!(Bank::membership_requests(1, 1).is_none());
+        assert_eq!(Bank::member_props(1, 1).unwrap().applicant(), 7);
+        approve_member(1, 1, 8);
+        assert!(Org::is_member_of_group(1, &7));
+        assert_eq!(Balances::total_balance(&7), 67);
+        assert_eq!(Bank::bank_balance(1), 40);
+    });
+}
+
+#[test]
+fn max_shares_per_member_works() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_eq!(Bank::max_shares_per_member_of(1), 10);
+        assert_noop!(
+            Bank::set_max_shares_per_member(Origin::signed(2), 1, Some(3)),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_max_shares_per_member(
+            Origin::signed(1),
+            1,
+            Some(3)
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MaxSharesPerMemberSet(1, Some(3))
+        );
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(1, 1, 8);
+        assert_eq!(Org::members(1, &7).unwrap().total(), 2);
+        // 7 holds 2 of its 3 so another 2 is approved but not minted
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(2, 2, 15);
+        assert_eq!(
+            Bank::member_props(1, 2).unwrap().state(),
+            ProposalState::ApprovedButNotExecuted
+        );
+        assert_eq!(Org::members(1, &7).unwrap().total(), 2);
+        assert_eq!(Balances::total_balance(&7), 67);
+        // the module default applies again once the override is removed
+        assert_ok!(Bank::set_max_shares_per_member(Origin::signed(1), 1, None));
+        assert_ok!(sponsor_applicant(2, 1, 10, 8, 7));
+        approve_member(3, 3, 22);
+        assert_eq!(
+            Bank::member_props(1, 3).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert_eq!(Org::members(1, &7).unwrap().total(), 10);
+    });
+}
+
+#[test]
+fn recurring_spend_pays_each_period() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::member_proposes_recurring_spend(
+                Origin::signed(1),
+                1,
+                20,
+                0,
+                3,
+                7
+            ),
+            Error::<Test>::RecurringSpendMustHavePeriods
+        );
+        assert_noop!(
+            Bank::member_proposes_recurring_spend(
+                Origin::signed(1),
+                1,
+                20,
+                3,
+                0,
+                7
+            ),
+            Error::<Test>::RecurringSpendMustHavePeriods
+        );
+        assert_ok!(Bank::member_proposes_recurring_spend(
+            Origin::signed(1),
+            1,
+            20,
+            3,
+            3,
+            7
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::RecurringSpendProposed(1, 1, 1, 20, 3, 3)
+        );
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // the first installment is paid in the block after approval
+        run_to_block(8);
+        assert_eq!(Balances::total_balance(&7), 97);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_noop!(
+            Bank::member_retries_spend_execution(Origin::signed(1), 1, 1),
+            Error::<Test>::CannotRetryRecurringSpend
+        );
+        run_to_block(11);
+        assert_eq!(Balances::total_balance(&7), 117);
+        assert_eq!(Bank::recurring_spends(1, 1).unwrap().remaining, 1);
+        // the bank cannot cover the last installment so it is missed
+        run_to_block(14);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::RecurringSpendPayout(1, 1, 3, 0))
+        }));
+        assert_eq!(Balances::total_balance(&7), 117);
+        assert_eq!(Bank::bank_balance(1), 10);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert!(Bank::recurring_spends(1, 1).is_none());
+    });
+}
+
+#[test]
+fn controller_vetoes_spend_in_grace_period() {
+    new_test_ext().execute_with(|| {
+        GraceWindow::set(3);
+        summon_default_bank();
+        let approve_spend = |spend_id: u64, poll_block: u64| {
+            assert_ok!(Bank::spend_trigger_vote(
+                Origin::signed(1),
+                1,
+                spend_id
+            ));
+            for i in 1u64..5u64 {
+                assert_ok!(Vote::submit_vote(
+                    Origin::signed(i),
+                    spend_id,
+                    VoterView::InFavor,
+                    None
+                ));
+            }
+            run_to_block(poll_block);
+        };
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        approve_spend(1, 7);
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendEnteredGracePeriod(1, 1, 10))
+        }));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::GracePeriod(1)
+        );
+        assert_eq!(Bank::spend_reservations(1, 1), Some(10));
+        assert_eq!(Balances::total_balance(&7), 77);
+        assert_noop!(
+            Bank::controller_vetoes_spend(Origin::signed(2), 1, 1),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        // no veto so the first poll after the window pays out
+        run_to_block(14);
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert!(Bank::spend_grace_ends(1, 1).is_none());
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 6, None));
+        approve_spend(2, 21);
+        assert_eq!(Bank::member_stats(1, 1).spends_passed, 2);
+        assert_noop!(
+            Bank::controller_vetoes_spend(Origin::signed(1), 1, 3),
+            Error::<Test>::CannotVetoSpendThatDNE
+        );
+        assert_ok!(Bank::controller_vetoes_spend(Origin::signed(1), 1, 2));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendProposalPolled(
+                    1,
+                    2,
+                    SpendState::Rejected(2),
+                    None,
+                    ResolutionTrigger::Sudo(1),
+                ))
+        }));
+        assert_eq!(get_last_event(), RawEvent::SpendVetoed(1, 1, 2));
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::Rejected(2)
+        );
+        assert_eq!(Bank::spend_reservations(1, 2), None);
+        // the veto takes back the credit given when the spend entered grace
+        assert_eq!(Bank::member_stats(1, 1).spends_passed, 1);
+        assert_noop!(
+            Bank::controller_vetoes_spend(Origin::signed(1), 1, 2),
+            Error::<Test>::CannotVetoSpendOutsideGracePeriod
+        );
+        run_to_block(28);
+        assert_eq!(Balances::total_balance(&6), 69);
+    });
+}
+
+#[test]
+fn membership_threshold_override_works() {
+    new_test_ext().execute_with(|| {
+        let threshold = |rep, percent| {
+            ThresholdInput::new(
+                rep,
+                XorThreshold::Percent(Threshold::new(
+                    Permill::from_percent(percent),
+                    None,
+                )),
+            )
+        };
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold(OrgRep::Equal(1), 50),
+            None
+        ));
+        // a proposer cannot weaken the bank's membership threshold
+        assert_noop!(
+            Bank::propose_member(
+                Origin::signed(1),
+                1,
+                0,
+                1,
+                0,
+                7,
+                None,
+                Some(threshold(OrgRep::Equal(1), 40))
+            ),
+            Error::<Test>::MemberThresholdOverrideTooWeak
+        );
+        assert_noop!(
+            Bank::propose_member(
+                Origin::signed(1),
+                1,
+                0,
+                1,
+                0,
+                7,
+                None,
+                Some(threshold(OrgRep::Weighted(1), 60))
+            ),
+            Error::<Test>::MemberThresholdOverrideTooWeak
+        );
+        assert_ok!(Bank::propose_member(
+            Origin::signed(1),
+            1,
+            0,
+            1,
+            0,
+            7,
+            None,
+            Some(threshold(OrgRep::Equal(1), 100))
+        ));
+        assert!(Bank::member_threshold_overrides(1, 1).is_some());
+        // five of six in favor passes the bank's threshold but not the proposal's
+        approve_member(1, 1, 8);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::Voting(1)
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(6),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        run_to_block(15);
+        assert_eq!(
+            Bank::member_props(1, 1).unwrap().state(),
+            ProposalState::ApprovedAndExecuted
+        );
+        assert!(Org::is_member_of_group(1, &7));
+    });
+}
+
+#[test]
+fn spend_cap_defers_spends_to_next_window() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_noop!(
+            Bank::set_spend_cap(Origin::signed(2), 1, Some((15, 10))),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_noop!(
+            Bank::set_spend_cap(Origin::signed(1), 1, Some((15, 0))),
+            Error::<Test>::SpendCapWindowMustBeNonZero
+        );
+        assert_ok!(Bank::set_spend_cap(Origin::signed(1), 1, Some((15, 10))));
+        assert_eq!(get_last_event(), RawEvent::SpendCapSet(1, Some((15, 10))));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 6, None));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(Balances::total_balance(&7), 87);
+        // a second 10 would exceed the 15 left in this window
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::SpendCapReached(1))
+        }));
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert!(Bank::cap_deferred_spends(1, 2));
+        run_to_block(7);
+        assert_eq!(Balances::total_balance(&6), 69);
+        // polling retries it once the window rolls over
+        run_to_block(14);
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&6), 79);
+        assert!(!Bank::cap_deferred_spends(1, 2));
+        assert_eq!(Bank::spend_window_usage(1), (14, 10));
+    });
+}
+
+#[test]
+fn new_member_shares_vest_before_use() {
+    new_test_ext().execute_with(|| {
+        ShareVestingPeriod::set(10);
+        summon_default_bank();
+        assert_ok!(sponsor_applicant(2, 1, 10, 2, 7));
+        approve_member(1, 1, 8);
+        assert!(Org::is_member_of_group(1, &7));
+        // locked for the vesting period from the block it executed in
+        let (locked, until) = Bank::locked_shares(1, 7).unwrap();
+        assert_eq!(locked, 2);
+        assert!(until > System::block_number());
+        assert!(System::events().into_iter().any(|r| {
+            r.event == TestEvent::bank(RawEvent::SharesLocked(1, 7, 2, until))
+        }));
+        assert_ok!(Bank::propose_spend(Origin::signed(7), 1, 10, 7, None));
+        assert_noop!(
+            Bank::spend_trigger_vote(Origin::signed(7), 1, 1),
+            Error::<Test>::NotPermittedToTriggerVoteForBankAccount
+        );
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(7), 1, 1, 20),
+            Error::<Test>::SharesLockedUntilVested
+        );
+        // both unlock once the vesting period has passed
+        run_to_block(until);
+        assert_eq!(Bank::locked_shares_of(1, &7), 0);
+        assert_ok!(Bank::member_ragequit(Origin::signed(7), 1, 1, 20));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(7), 1, 1));
+    });
+}
+
+#[test]
+fn locked_bank_funds_block_spend_execution() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        Balances::set_lock(
+            *b"testlock",
+            &Bank::bank_account_id(1),
+            45,
+            WithdrawReasons::all(),
+        );
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        let blocked: DispatchError =
+            pallet_balances::Error::<Test>::LiquidityRestrictions.into();
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendWithdrawalBlocked(
+                    1, 1, blocked,
+                ))
+        }));
+        assert_eq!(Balances::total_balance(&7), 77);
+        // executes once the lock is lifted
+        Balances::remove_lock(*b"testlock", &Bank::bank_account_id(1));
+        assert_ok!(Bank::member_retries_spend_execution(
+            Origin::signed(1),
+            1,
+            1
+        ));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+    });
+}
+
+#[test]
+fn convicted_minority_outweighs_unconvicted_majority() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(60),
+                Some(Permill::from_percent(60)),
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold,
+            None
+        ));
+        // spend votes only accept conviction once the bank enables it
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_noop!(
+            Vote::submit_conviction_vote(
+                Origin::signed(5),
+                1,
+                VoterView::Against,
+                Conviction::Locked2x,
+                None
+            ),
+            vote::Error::<Test>::VoteDoesNotAcceptConviction
+        );
+        assert_noop!(
+            Bank::set_conviction_enabled(Origin::signed(2), 1, true),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_ok!(Bank::set_conviction_enabled(Origin::signed(1), 1, true));
+        assert_eq!(get_last_event(), RawEvent::ConvictionEnabledSet(1, true));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 6, None));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        // three of six in favor is short of the 60% threshold
+        for i in 2u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // two members locking their shares double their signal against
+        assert_ok!(Vote::submit_conviction_vote(
+            Origin::signed(5),
+            2,
+            VoterView::Against,
+            Conviction::Locked2x,
+            None
+        ));
+        assert_noop!(
+            Vote::submit_conviction_vote(
+                Origin::signed(5),
+                2,
+                VoterView::InFavor,
+                Conviction::Locked2x,
+                None
+            ),
+            vote::Error::<Test>::ConvictionOnlyOnFirstVote
+        );
+        assert_ok!(Vote::submit_conviction_vote(
+            Origin::signed(6),
+            2,
+            VoterView::Against,
+            Conviction::Locked2x,
+            None
+        ));
+        assert_eq!(Vote::tally(2), Some((3, 4)));
+        run_to_block(7);
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::Rejected(2)
+        );
+        // locked shares cannot ragequit until the lock expires
+        let until = Vote::conviction_locks(1, 5).unwrap();
+        assert_noop!(
+            Bank::member_ragequit(Origin::signed(5), 1, 1, 20),
+            Error::<Test>::SharesLockedByConvictionVote
+        );
+        run_to_block(until);
+        assert_ok!(Bank::member_ragequit(Origin::signed(5), 1, 1, 20));
+    });
+}
+
+#[test]
+fn get_org_banks_orders_by_id() {
+    new_test_ext().execute_with(|| {
+        MaxBanksPerOrg::set(3);
+        assert_eq!(
+            Bank::get_org_banks(1),
+            Err(Error::<Test>::NoBanksForOrg.into())
+        );
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        for _ in 0..3 {
+            assert_ok!(Bank::summon(
+                Origin::signed(1),
+                1,
+                20,
+                None,
+                threshold.clone(),
+                None
+            ));
+        }
+        assert_eq!(Bank::get_org_banks(1), Ok(vec![1, 2, 3]));
+        // the wrapper picks the lowest id rather than the last one iterated
+        #[allow(deprecated)]
+        let first = Bank::get_org_bank(1);
+        assert_eq!(first, Ok(1));
+        assert_ok!(Bank::sudo_force_close_bank(Origin::root(), 1, 20, 20));
+        assert_eq!(Bank::get_org_banks(1), Ok(vec![2, 3]));
+    });
+}
+
+#[test]
+fn polled_events_record_resolution_trigger() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        for _ in 0..3 {
+            assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 5, 7, None));
+        }
+        assert_ok!(sponsor_applicant(2, 1, 10, 1, 7));
+        let polled_by =
+            |e: TestEvent| System::events().into_iter().any(|r| r.event == e);
+        // the controller approves the first spend directly
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert!(polled_by(TestEvent::bank(RawEvent::SpendProposalPolled(
+            1,
+            1,
+            SpendState::ApprovedAndExecuted,
+            None,
+            ResolutionTrigger::Sudo(1),
+        ))));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 2));
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 3));
+        assert_ok!(Bank::member_trigger_vote(Origin::signed(1), 1, 1));
+        let approve = |vote_id: u64| {
+            for i in 1u64..5u64 {
+                assert_ok!(Vote::submit_vote(
+                    Origin::signed(i),
+                    vote_id,
+                    VoterView::InFavor,
+                    None
+                ));
+            }
+        };
+        approve(1);
+        approve(3);
+        // a member processes the second spend and another pokes the membership
+        assert_ok!(Bank::member_processes_spend(Origin::signed(3), 1, 2));
+        assert!(polled_by(TestEvent::bank(RawEvent::SpendProposalPolled(
+            1,
+            2,
+            SpendState::ApprovedAndExecuted,
+            Some((4, 0)),
+            ResolutionTrigger::Member(3),
+        ))));
+        assert_ok!(Bank::poke_concluded(Origin::signed(6), 5));
+        assert!(System::events().into_iter().any(|r| {
+            matches!(
+                r.event,
+                TestEvent::bank(RawEvent::MemberProposalPolled(
+                    1,
+                    1,
+                    _,
+                    _,
+                    ResolutionTrigger::Member(6)
+                ))
+            )
+        }));
+        // the third spend is left to scheduled polling
+        approve(2);
+        run_to_block(7);
+        assert!(polled_by(TestEvent::bank(RawEvent::SpendProposalPolled(
+            1,
+            3,
+            SpendState::ApprovedAndExecuted,
+            Some((4, 0)),
+            ResolutionTrigger::Auto,
+        ))));
+    });
+}
+
+#[test]
+fn merge_banks_consolidates_same_org_banks() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(
+                Permill::from_percent(50),
+                None,
+            )),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            50,
+            Some(1),
+            threshold.clone(),
+            None
+        ));
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            Some(1),
+            threshold,
+            None
+        ));
+        assert_ok!(Org::register_organization(
+            OrganizationSource::Accounts(vec![1, 2, 3]),
+            None,
+            10
+        ));
+        let threshold2 = ThresholdInput::new(
+            OrgRep::Equal(2),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            2,
+            20,
+            Some(1),
+            threshold2,
+            None
+        ));
+        assert_noop!(
+            Bank::merge_banks(Origin::signed(1), 2, 2, 20),
+            Error::<Test>::CannotMergeBankIntoItself
+        );
+        assert_noop!(
+            Bank::merge_banks(Origin::signed(2), 2, 1, 20),
+            Error::<Test>::NotPermittedToConfigureBankAccount
+        );
+        assert_noop!(
+            Bank::merge_banks(Origin::signed(1), 3, 1, 20),
+            Error::<Test>::CannotMergeBanksOfDifferentOrgs
+        );
+        // proposals in the source bank must resolve first
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 2, 5, 7, None));
+        assert_noop!(
+            Bank::merge_banks(Origin::signed(1), 2, 1, 20),
+            Error::<Test>::CannotMergeBankWithLiveProposals
+        );
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 2, 1));
+        assert_ok!(Bank::merge_banks(Origin::signed(1), 2, 1, 20));
+        assert_eq!(get_last_event(), RawEvent::BanksMerged(2, 1, 15));
+        assert!(Bank::bank_stores(2).is_none());
+        assert_eq!(Bank::bank_balance(1), 65);
+        assert_eq!(Bank::get_org_banks(1), Ok(vec![1]));
+        assert_eq!(Bank::org_bank_count(1), 1);
+        assert_eq!(Balances::total_balance(&7), 82);
+    });
+}
+
+#[test]
+fn proposal_states_classify_consistently() {
+    let spend_states: Vec<(SpendState<u64>, bool, bool, bool)> = vec![
+        // state, terminal, voting, pending
+        (SpendState::WaitingForApproval, false, false, true),
+        (SpendState::Voting(1), false, true, false),
+        (SpendState::FailedQuorum(1), false, false, true),
+        (SpendState::GracePeriod(1), false, false, true),
+        (SpendState::ApprovedButNotExecuted, false, false, true),
+        (SpendState::Rejected(1), true, false, false),
+        (SpendState::ApprovedAndExecuted, true, false, false),
+        (SpendState::Expired, true, false, false),
+    ];
+    for (state, terminal, voting, pending) in spend_states {
+        assert_eq!(state.is_terminal(), terminal, "{:?}", state);
+        assert_eq!(state.is_voting(), voting, "{:?}", state);
+        assert_eq!(state.is_pending(), pending, "{:?}", state);
+    }
+    let member_states: Vec<(ProposalState<u64>, bool, bool, bool)> = vec![
+        (ProposalState::WaitingForApproval, false, false, true),
+        (ProposalState::Voting(1), false, true, false),
+        (ProposalState::FailedQuorum(1), false, false, true),
+        (ProposalState::ApprovedButNotExecuted, true, false, false),
+        (ProposalState::Rejected(1), true, false, false),
+        (ProposalState::ApprovedAndExecuted, true, false, false),
+        (ProposalState::Expired, true, false, false),
+    ];
+    for (state, terminal, voting, pending) in member_states {
+        assert_eq!(state.is_terminal(), terminal, "{:?}", state);
+        assert_eq!(state.is_voting(), voting, "{:?}", state);
+        assert_eq!(state.is_pending(), pending, "{:?}", state);
+    }
+}
+
+#[test]
+fn amend_spend_proposal_only_before_voting() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        assert_ok!(Bank::propose_spend(Origin::signed(2), 1, 10, 7, None));
+        assert_noop!(
+            Bank::amend_spend_proposal(Origin::signed(3), 1, 1, Some(12), None),
+            Error::<Test>::NotPermittedToAmendSpendProposal
+        );
+        assert_noop!(
+            Bank::amend_spend_proposal(Origin::signed(2), 1, 1, None, None),
+            Error::<Test>::SpendAmendmentMustChangeAmountOrDest
+        );
+        assert_noop!(
+            Bank::amend_spend_proposal(Origin::signed(2), 1, 1, Some(4), None),
+            Error::<Test>::SpendBelowMinimum
+        );
+        assert_ok!(Bank::amend_spend_proposal(
+            Origin::signed(2),
+            1,
+            1,
+            Some(12),
+            Some(6)
+        ));
+        assert_eq!(get_last_event(), RawEvent::SpendProposalAmended(1, 1));
+        let prop = Bank::spend_props(1, 1).unwrap();
+        assert_eq!((prop.amount(), prop.dest()), (12, 6));
+        assert_eq!(prop.state(), SpendState::WaitingForApproval);
+        // frozen once voters are deciding on it
+        assert_ok!(Bank::spend_trigger_vote(Origin::signed(1), 1, 1));
+        assert_noop!(
+            Bank::amend_spend_proposal(Origin::signed(2), 1, 1, Some(10), None),
+            Error::<Test>::CannotAmendSpendProposalOnceVoteTriggered
+        );
+        assert_noop!(
+            Bank::amend_spend_proposal(Origin::signed(2), 1, 2, Some(10), None),
+            Error::<Test>::CannotAmendSpendProposalThatDNE
+        );
+    });
+}
+
+#[test]
+fn summon_policy_can_require_org_supervisor() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(1),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        // by default any member may open a bank
+        assert_ok!(Bank::summon(
+            Origin::signed(2),
+            1,
+            20,
+            None,
+            threshold.clone(),
+            None
+        ));
+        RequireSupervisorToSummon::set(true);
+        assert_noop!(
+            Bank::summon(
+                Origin::signed(3),
+                1,
+                20,
+                None,
+                threshold.clone(),
+                None
+            ),
+            Error::<Test>::NotPermittedToOpenBankAccountForOrg
+        );
+        // account 1 supervises the org
+        assert_ok!(Bank::summon(
+            Origin::signed(1),
+            1,
+            20,
+            None,
+            threshold,
+            None
+        ));
+        assert_eq!(Bank::get_org_banks(1), Ok(vec![1, 2]));
+    });
+}
+
+#[test]
+fn summon_for_unregistered_org_fails() {
+    new_test_ext().execute_with(|| {
+        let threshold = ThresholdInput::new(
+            OrgRep::Equal(9),
+            XorThreshold::Percent(Threshold::new(Permill::one(), None)),
+        );
+        assert_noop!(
+            Bank::summon(Origin::signed(1), 9, 20, None, threshold, None),
+            Error::<Test>::OrgDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn spend_fee_routes_share_of_executed_spends() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        // zero fee pays the full amount
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 87);
+        assert_eq!(Bank::spend_fee_charged(1, 1), None);
+        // nonzero fee is taken from the gross amount
+        SpendFee::set(Permill::from_percent(10));
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 2));
+        assert_eq!(
+            Bank::spend_props(1, 2).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Bank::spend_props(1, 2).unwrap().amount(), 10);
+        assert_eq!(Balances::total_balance(&7), 96);
+        assert_eq!(Balances::total_balance(&FeeDestination::get()), 1);
+        assert_eq!(Bank::spend_fee_charged(1, 2), Some(1));
+        assert!(System::events().into_iter().any(|r| {
+            r.event
+                == TestEvent::bank(RawEvent::SpendFeeCharged(
+                    1,
+                    2,
+                    1,
+                    FeeDestination::get(),
+                ))
+        }));
+    });
+}
+
+#[test]
+fn unpayable_spend_fee_leaves_spend_approved_but_not_executed() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        SpendFee::set(Permill::from_percent(10));
+        // the fee alone cannot open the fee destination's account
+        ExistentialDeposit::set(5);
+        assert_ok!(Bank::propose_spend(Origin::signed(1), 1, 10, 7, None));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedButNotExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 77);
+        assert_eq!(Balances::total_balance(&FeeDestination::get()), 0);
+        assert_eq!(Bank::spend_fee_charged(1, 1), None);
+    });
+}
+
+#[test]
+fn spend_fee_applies_to_every_split_leg() {
+    new_test_ext().execute_with(|| {
+        summon_default_bank();
+        SpendFee::set(Permill::from_percent(10));
+        assert_ok!(Bank::member_proposes_split_spend(
+            Origin::signed(2),
+            1,
+            vec![(7, 10), (6, 20)]
+        ));
+        assert_ok!(Bank::sudo_approve_spend_proposal(Origin::signed(1), 1, 1));
+        assert_eq!(
+            Bank::spend_props(1, 1).unwrap().state(),
+            SpendState::ApprovedAndExecuted
+        );
+        assert_eq!(Balances::total_balance(&7), 86);
+        assert_eq!(Balances::total_balance(&6), 87);
+        // fees of both legs accrue to the same record
+        assert_eq!(Balances::total_balance(&FeeDestination::get()), 3);
+        assert_eq!(Bank::spend_fee_charged(1, 1), Some(3));
+    });
+}
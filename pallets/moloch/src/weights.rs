@@ -0,0 +1,150 @@
+//! Weights for the moloch pallet
+//!
+//! The default implementation is estimated from the storage reads and writes
+//! each call performs, so runtimes should plug in benchmarked values
+
+use frame_support::{
+    traits::Get,
+    weights::{
+        constants::RocksDbWeight as DbWeight,
+        Weight,
+    },
+};
+
+pub trait WeightInfo {
+    fn summon() -> Weight;
+    fn batch_summon(banks: u32) -> Weight;
+    fn member_funds_bank() -> Weight;
+    fn propose_spend() -> Weight;
+    fn member_proposes_conditional_spend() -> Weight;
+    fn member_proposes_split_spend(legs: u32) -> Weight;
+    fn member_proposes_recurring_spend() -> Weight;
+    fn member_cancels_spend() -> Weight;
+    fn controller_vetoes_spend() -> Weight;
+    fn propose_member() -> Weight;
+    fn submit_membership_request() -> Weight;
+    fn withdraw_membership_request() -> Weight;
+    fn propose_guild_kick() -> Weight;
+    fn trigger_vote() -> Weight;
+    fn set_delegate() -> Weight;
+    fn sudo_approve_spend_proposal() -> Weight;
+    /// Resolving a concluded vote and paying the processing reward
+    fn process_proposal() -> Weight;
+    fn member_retries_spend_execution() -> Weight;
+    /// Scales with the bank's active spend proposals checked for the caller's votes
+    fn ragequit(live_spends: u32) -> Weight;
+    fn claim_loot() -> Weight;
+    fn claim_dissolution_proceeds() -> Weight;
+    /// Controller-only setters that read the bank and write one config item
+    fn configure_bank() -> Weight;
+    fn set_bank_thresholds() -> Weight;
+    fn set_poll_frequency() -> Weight;
+    /// Scales with the holders paid out and the proposals cleared
+    fn close_org_bank_account(members: u32, proposals: u32) -> Weight;
+    fn poke_concluded(max: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+    fn summon() -> Weight {
+        // org bank count, org, bank id nonce, bank, threshold, deposit
+        60_000_000 + DbWeight::get().reads_writes(6, 7)
+    }
+    fn batch_summon(banks: u32) -> Weight {
+        Self::summon().saturating_mul(banks.max(1) as Weight)
+    }
+    fn member_funds_bank() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(4, 2)
+    }
+    fn propose_spend() -> Weight {
+        // allowlist, cooloff, fee and bond, then the proposal and its side maps
+        50_000_000 + DbWeight::get().reads_writes(9, 9)
+    }
+    fn member_proposes_conditional_spend() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(10, 11)
+    }
+    fn member_proposes_split_spend(legs: u32) -> Weight {
+        55_000_000
+            + (legs as Weight).saturating_mul(1_000_000)
+            + DbWeight::get().reads_writes(9, 10)
+    }
+    fn member_proposes_recurring_spend() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(9, 10)
+    }
+    fn member_cancels_spend() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(5, 6)
+    }
+    fn controller_vetoes_spend() -> Weight {
+        // releases the reservation and clears the custom payout records
+        40_000_000 + DbWeight::get().reads_writes(5, 10)
+    }
+    fn propose_member() -> Weight {
+        // cooloff, sponsor bond, fee, tribute escrow, then the proposal
+        50_000_000 + DbWeight::get().reads_writes(10, 7)
+    }
+    fn submit_membership_request() -> Weight {
+        // bank, tribute escrow, request nonce, then the request
+        35_000_000 + DbWeight::get().reads_writes(3, 3)
+    }
+    fn withdraw_membership_request() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(2, 2)
+    }
+    fn propose_guild_kick() -> Weight {
+        // open proposal cap, fee, then the proposal and its expiry
+        35_000_000 + DbWeight::get().reads_writes(9, 8)
+    }
+    fn trigger_vote() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(6, 4)
+    }
+    fn set_delegate() -> Weight {
+        // walks the delegate's chain to rule out a cycle
+        30_000_000 + DbWeight::get().reads_writes(6, 3)
+    }
+    fn sudo_approve_spend_proposal() -> Weight {
+        45_000_000 + DbWeight::get().reads_writes(8, 6)
+    }
+    fn process_proposal() -> Weight {
+        55_000_000 + DbWeight::get().reads_writes(9, 8)
+    }
+    fn member_retries_spend_execution() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(6, 3)
+    }
+    fn ragequit(live_spends: u32) -> Weight {
+        // each active spend and, if in a vote, its voters are read
+        60_000_000
+            + DbWeight::get().reads_writes(9, 4)
+            + (live_spends as Weight)
+                .saturating_mul(2_000_000 + DbWeight::get().reads(2))
+    }
+    fn claim_loot() -> Weight {
+        40_000_000 + DbWeight::get().reads_writes(6, 4)
+    }
+    fn claim_dissolution_proceeds() -> Weight {
+        35_000_000 + DbWeight::get().reads_writes(3, 3)
+    }
+    fn configure_bank() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(1, 1)
+    }
+    fn set_bank_thresholds() -> Weight {
+        30_000_000 + DbWeight::get().reads_writes(2, 4)
+    }
+    fn set_poll_frequency() -> Weight {
+        20_000_000 + DbWeight::get().reads_writes(1, 2)
+    }
+    fn close_org_bank_account(members: u32, proposals: u32) -> Weight {
+        // a transfer to each holder, then clearing each proposal's items and
+        // every per-bank item
+        100_000_000
+            + DbWeight::get().reads_writes(20, 40)
+            + (members as Weight)
+                .saturating_mul(25_000_000 + DbWeight::get().reads_writes(3, 2))
+            + (proposals as Weight)
+                .saturating_mul(5_000_000 + DbWeight::get().reads_writes(2, 12))
+    }
+    fn poke_concluded(max: u32) -> Weight {
+        // each of the `max` lists or proposals read may also be advanced and
+        // deactivated
+        10_000_000
+            + (max as Weight)
+                .saturating_mul(30_000_000 + DbWeight::get().reads_writes(5, 6))
+    }
+}
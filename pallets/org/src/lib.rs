@@ -347,6 +347,9 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    pub fn org_exists(org: T::OrgId) -> bool {
+        <Orgs<T>>::contains_key(org)
+    }
     pub fn is_immediate_child(parent: T::OrgId, child: T::OrgId) -> bool {
         <OrgTree<T>>::get(parent, child).is_some()
     }
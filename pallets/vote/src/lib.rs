@@ -25,6 +25,7 @@ use frame_support::{
     decl_module,
     decl_storage,
     ensure,
+    storage::IterableStorageDoubleMap,
     Parameter,
 };
 use frame_system::{
@@ -39,6 +40,7 @@ use sp_runtime::{
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
@@ -56,7 +58,9 @@ use util::{
         Apply,
         ApplyVote,
         CheckVoteStatus,
+        CloseVote,
         ConfigureThreshold,
+        ConvictionVote,
         GenerateUniqueID,
         GetGroup,
         GetVoteOutcome,
@@ -67,9 +71,11 @@ use util::{
         ShareInformation,
         UpdateVote,
         VoteOnProposal,
+        VoteParticipation,
         VoteVector,
     },
     vote::{
+        Conviction,
         Threshold,
         ThresholdConfig,
         ThresholdInput,
@@ -152,6 +158,8 @@ decl_event!(
         ThresholdSet(ThresholdId),
         NewVoteStarted(AccountId, VoteId),
         Voted(VoteId, AccountId, VoterView),
+        ConvictionVoted(VoteId, AccountId, VoterView, Conviction),
+        VoteClosed(VoteId),
     }
 );
 
@@ -171,6 +179,9 @@ decl_error! {
         InputThresholdExceedsBounds,
         OnlySupervisorCanSetGenericThresholds,
         CannotInvokeThresholdThatDNE,
+        CannotCloseVoteThatDNE,
+        VoteDoesNotAcceptConviction,
+        ConvictionOnlyOnFirstVote,
     }
 }
 
@@ -201,6 +212,16 @@ decl_storage! {
         pub VoteLogger get(fn vote_logger): double_map
             hasher(blake2_128_concat) T::VoteId,
             hasher(blake2_128_concat) T::AccountId  => Option<VoteVec<T>>;
+
+        /// Votes that accept conviction, with the org whose shares are locked and
+        /// the length of one lock period
+        pub ConvictionVotes get(fn conviction_votes): map
+            hasher(blake2_128_concat) T::VoteId => Option<(T::OrgId, T::BlockNumber)>;
+
+        /// The block until which conviction votes lock a member's shares in an org
+        pub ConvictionLocks get(fn conviction_locks): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::BlockNumber>;
     }
 }
 
@@ -281,6 +302,19 @@ decl_module! {
             Self::deposit_event(RawEvent::Voted(vote_id, voter, direction));
             Ok(())
         }
+        #[weight = 0]
+        pub fn submit_conviction_vote(
+            origin,
+            vote_id: T::VoteId,
+            direction: VoterView,
+            conviction: Conviction,
+            justification: Option<T::Cid>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            Self::vote_with_conviction(vote_id, &voter, direction, conviction, justification)?;
+            Self::deposit_event(RawEvent::ConvictionVoted(vote_id, voter, direction, conviction));
+            Ok(())
+        }
     }
 }
 
@@ -310,6 +344,57 @@ impl<T: Trait> Module<T> {
         };
         Threshold::new(in_favor_t, against_t)
     }
+    /// Casts a first vote with signal multiplied by the conviction and locks the
+    /// voter's shares for the conviction's lock periods
+    fn vote_with_conviction(
+        vote_id: T::VoteId,
+        voter: &T::AccountId,
+        direction: VoterView,
+        conviction: Conviction,
+        justification: Option<T::Cid>,
+    ) -> DispatchResult {
+        let (org, lock_period) = <ConvictionVotes<T>>::get(vote_id)
+            .ok_or(Error::<T>::VoteDoesNotAcceptConviction)?;
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+        let old_vote = <VoteLogger<T>>::get(vote_id, voter)
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        ensure!(
+            old_vote.direction() == VoterView::Uninitialized,
+            Error::<T>::ConvictionOnlyOnFirstVote
+        );
+        let magnitude = old_vote
+            .magnitude()
+            .saturating_mul(T::Signal::from(conviction.multiplier()));
+        let new_state = Self::apply_vote(
+            vote_state,
+            magnitude,
+            VoterView::Uninitialized,
+            direction,
+        )
+        .ok_or(Error::<T>::VoteChangeNotSupported)?;
+        <VoteLogger<T>>::insert(
+            vote_id,
+            voter,
+            Vote::new(magnitude, direction, justification),
+        );
+        <VoteStates<T>>::insert(vote_id, new_state);
+        if conviction.lock_periods() > 0 {
+            let until = <frame_system::Module<T>>::block_number()
+                .saturating_add(
+                    lock_period
+                        .saturating_mul(conviction.lock_periods().into()),
+                );
+            <ConvictionLocks<T>>::mutate(org, voter, |lock| {
+                *lock = Some(lock.map_or(until, |l| l.max(until)))
+            });
+        }
+        Ok(())
+    }
     fn generate_threshold_uid() -> T::ThresholdId {
         let mut thresh_counter = <ThresholdIdCounter<T>>::get() + 1u32.into();
         while <VoteThresholds<T>>::get(thresh_counter).is_some() {
@@ -348,6 +433,29 @@ impl<T: Trait> GetVoteOutcome<T::VoteId> for Module<T> {
     }
 }
 
+impl<T: Trait> VoteParticipation<T::VoteId, T::AccountId, T::Signal>
+    for Module<T>
+{
+    fn turnout(vote_id: T::VoteId) -> Option<(T::Signal, T::Signal)> {
+        <VoteStates<T>>::get(vote_id)
+            .map(|v| (v.turnout(), v.all_possible_turnout()))
+    }
+    fn tally(vote_id: T::VoteId) -> Option<(T::Signal, T::Signal)> {
+        <VoteStates<T>>::get(vote_id).map(|v| (v.in_favor(), v.against()))
+    }
+    fn awaits_vote(vote_id: T::VoteId, who: &T::AccountId) -> bool {
+        <VoteLogger<T>>::get(vote_id, who)
+            .map(|v| v.direction() == VoterView::Uninitialized)
+            .unwrap_or(false)
+    }
+    fn voters(vote_id: T::VoteId) -> Vec<T::AccountId> {
+        <VoteLogger<T>>::iter_prefix(vote_id)
+            .filter(|(_, v)| v.direction() != VoterView::Uninitialized)
+            .map(|(who, _)| who)
+            .collect()
+    }
+}
+
 impl<T: Trait> ConfigureThreshold<ThreshInput<T>, T::Cid, T::BlockNumber>
     for Module<T>
 {
@@ -361,6 +469,11 @@ impl<T: Trait> ConfigureThreshold<ThreshInput<T>, T::Cid, T::BlockNumber>
         <VoteThresholds<T>>::insert(id, threshold);
         Ok(id)
     }
+    fn threshold(id: T::ThresholdId) -> Option<ThreshInput<T>> {
+        <VoteThresholds<T>>::get(id).map(|config| {
+            ThreshInput::<T>::new(config.org(), config.threshold())
+        })
+    }
     fn invoke_threshold(
         id: T::ThresholdId,
         topic: Option<T::Cid>,
@@ -554,6 +667,45 @@ impl<T: Trait> MintableSignal<T::AccountId, T::OrgId, T::VoteId, T::Signal>
     }
 }
 
+impl<T: Trait> CloseVote<T::VoteId> for Module<T> {
+    fn close_vote(vote_id: T::VoteId) -> DispatchResult {
+        ensure!(
+            <VoteStates<T>>::contains_key(vote_id),
+            Error::<T>::CannotCloseVoteThatDNE
+        );
+        <VoteStates<T>>::remove(vote_id);
+        <TotalSignalIssuance<T>>::remove(vote_id);
+        <VoteLogger<T>>::remove_prefix(vote_id);
+        <ConvictionVotes<T>>::remove(vote_id);
+        <OpenVoteCounter>::mutate(|count| *count = count.saturating_sub(1));
+        Self::deposit_event(RawEvent::VoteClosed(vote_id));
+        Ok(())
+    }
+}
+
+impl<T: Trait> ConvictionVote<T::OrgId, T::VoteId, T::AccountId, T::BlockNumber>
+    for Module<T>
+{
+    fn enable_conviction(
+        vote_id: T::VoteId,
+        org: T::OrgId,
+        lock_period: T::BlockNumber,
+    ) -> DispatchResult {
+        ensure!(
+            <VoteStates<T>>::contains_key(vote_id),
+            Error::<T>::NoVoteStateForVoteRequest
+        );
+        <ConvictionVotes<T>>::insert(vote_id, (org, lock_period));
+        Ok(())
+    }
+    fn conviction_locked_until(
+        org: T::OrgId,
+        who: &T::AccountId,
+    ) -> Option<T::BlockNumber> {
+        <ConvictionLocks<T>>::get(org, who)
+    }
+}
+
 impl<T: Trait> ApplyVote<T::Cid> for Module<T> {
     type Signal = T::Signal;
     type Direction = VoterView;
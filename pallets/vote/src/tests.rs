@@ -202,6 +202,35 @@ fn vote_pct_threshold_works() {
     });
 }
 
+#[test]
+fn closing_vote_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Vote::create_percent_vote(
+            one.clone(),
+            None,
+            OrgRep::Equal(1),
+            Threshold::new(Permill::from_percent(50), None),
+            None
+        ));
+        assert_ok!(Vote::submit_vote(one.clone(), 1, VoterView::InFavor, None));
+        assert_eq!(Vote::open_vote_counter(), 1);
+        assert_ok!(Vote::close_vote(1));
+        assert_eq!(get_last_event(), RawEvent::VoteClosed(1));
+        assert_eq!(Vote::open_vote_counter(), 0);
+        assert!(Vote::vote_states(1).is_none());
+        assert!(Vote::vote_logger(1, 1).is_none());
+        assert_noop!(
+            Vote::submit_vote(one, 1, VoterView::Against, None),
+            Error::<Test>::NoVoteStateForVoteRequest
+        );
+        assert_noop!(
+            Vote::close_vote(1),
+            Error::<Test>::CannotCloseVoteThatDNE
+        );
+    });
+}
+
 #[test]
 fn changing_votes_upholds_invariants() {
     new_test_ext().execute_with(|| {
@@ -18,10 +18,13 @@ pub struct BankState<
     id: BankId,
     // Registered organization identifier
     org: OrgId,
-    // Layered sudo, selection should eventually be revocable by the group
+    // Layered sudo, handed off or revoked by the current controller
     controller: Option<AccountId>,
     // identifier for registered vote threshold
     threshold_id: ThresholdId,
+    // human-readable label, unnamed by default
+    #[new(default)]
+    name: Option<Vec<u8>>,
 }
 
 impl<
@@ -43,6 +46,21 @@ impl<
     pub fn threshold_id(&self) -> ThresholdId {
         self.threshold_id
     }
+    pub fn name(&self) -> Option<Vec<u8>> {
+        self.name.clone()
+    }
+    pub fn set_name(&self, name: Option<Vec<u8>>) -> Self {
+        Self {
+            name,
+            ..self.clone()
+        }
+    }
+    pub fn set_controller(&self, controller: Option<AccountId>) -> Self {
+        Self {
+            controller,
+            ..self.clone()
+        }
+    }
     pub fn is_org(&self, org: OrgId) -> bool {
         org == self.org()
     }
@@ -61,8 +79,105 @@ impl<
 pub enum SpendState<VoteId> {
     WaitingForApproval,
     Voting(VoteId),
+    // vote passed on support but turnout did not meet the bank's quorum
+    FailedQuorum(VoteId),
+    Rejected(VoteId),
     ApprovedButNotExecuted,
     ApprovedAndExecuted,
+    // outlived its expiry without a live vote and was removed
+    Expired,
+    // approved by the vote but may still be vetoed by the controller until the
+    // bank's grace window ends
+    GracePeriod(VoteId),
+}
+
+impl<VoteId> SpendState<VoteId> {
+    /// Resolved for good, no vote or execution can follow
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            SpendState::Rejected(_)
+                | SpendState::ApprovedAndExecuted
+                | SpendState::Expired
+        )
+    }
+    /// In a live vote
+    pub fn is_voting(&self) -> bool {
+        matches!(self, SpendState::Voting(_))
+    }
+    /// Awaiting a vote, the end of the grace window or a retried execution
+    pub fn is_pending(&self) -> bool {
+        !self.is_terminal() && !self.is_voting()
+    }
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Where a spend proposer's bond goes when the spend is rejected
+pub enum SlashedBondDestination {
+    /// Repatriated to the bank account as revenue
+    Bank,
+    /// Burned, reducing total issuance
+    Burn,
+    /// Handed to the runtime's treasury handler
+    Treasury,
+}
+
+impl Default for SlashedBondDestination {
+    fn default() -> SlashedBondDestination {
+        SlashedBondDestination::Bank
+    }
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Where the residual left in a bank after the close distribution goes
+pub enum CloseDustDestination {
+    /// Transferred to the account closing the bank
+    Closer,
+    /// Burned, reducing total issuance
+    Burn,
+}
+
+impl Default for CloseDustDestination {
+    fn default() -> CloseDustDestination {
+        CloseDustDestination::Closer
+    }
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Predicate checked when an approved conditional spend would execute
+pub enum SpendCondition<BlockNumber, Key> {
+    /// Holds from the given block onwards
+    AfterBlock(BlockNumber),
+    /// Holds while the runtime's external flag for the key is set
+    IfExternalFlag(Key),
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Installments of a recurring spend, paid out once the spend is approved
+pub struct RecurringSchedule<Balance, BlockNumber> {
+    pub amount_per_period: Balance,
+    pub period: BlockNumber,
+    pub num_periods: u32,
+    pub remaining: u32,
+    /// Block of the next installment, `None` until the spend is approved
+    pub next_payout: Option<BlockNumber>,
+}
+
+#[derive(
+    Clone, Copy, Eq, PartialEq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Metadata of a live spend proposal shown to members and observers
+pub struct SpendDetail<AccountId, Balance> {
+    pub proposer: Option<AccountId>,
+    pub bond: Option<Balance>,
 }
 
 #[derive(
@@ -70,6 +185,8 @@ pub enum SpendState<VoteId> {
 )]
 pub struct SpendProposal<BankId, SpendId, Currency, AccountId, State> {
     id: (BankId, SpendId),
+    // member who requested the spend
+    proposer: AccountId,
     amount: Currency,
     dest: AccountId,
     state: State,
@@ -86,11 +203,13 @@ impl<
     pub fn new(
         bank_id: BankId,
         spend_id: SpendId,
+        proposer: AccountId,
         amount: Currency,
         dest: AccountId,
     ) -> Self {
         Self {
             id: (bank_id, spend_id),
+            proposer,
             amount,
             dest,
             state: SpendState::WaitingForApproval,
@@ -102,6 +221,9 @@ impl<
     pub fn spend_id(&self) -> SpendId {
         self.id.1
     }
+    pub fn proposer(&self) -> AccountId {
+        self.proposer.clone()
+    }
     pub fn amount(&self) -> Currency {
         self.amount
     }
@@ -117,4 +239,11 @@ impl<
             ..self.clone()
         }
     }
+    pub fn amend(&self, amount: Currency, dest: AccountId) -> Self {
+        Self {
+            amount,
+            dest,
+            ..self.clone()
+        }
+    }
 }
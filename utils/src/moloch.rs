@@ -11,8 +11,35 @@ use sp_std::prelude::*;
 pub enum ProposalState<VoteId> {
     WaitingForApproval,
     Voting(VoteId),
+    // vote passed on support but turnout did not meet the bank's quorum
+    FailedQuorum(VoteId),
+    Rejected(VoteId),
     ApprovedButNotExecuted,
     ApprovedAndExecuted,
+    // outlived its expiry without a live vote and was removed
+    Expired,
+}
+
+impl<VoteId> ProposalState<VoteId> {
+    /// Resolved for good. Unlike spends, an approved proposal that could not be
+    /// executed is not retried and must be proposed again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ProposalState::Rejected(_)
+                | ProposalState::ApprovedButNotExecuted
+                | ProposalState::ApprovedAndExecuted
+                | ProposalState::Expired
+        )
+    }
+    /// In a live vote
+    pub fn is_voting(&self) -> bool {
+        matches!(self, ProposalState::Voting(_))
+    }
+    /// Awaiting a vote, including a fresh one after failing quorum
+    pub fn is_pending(&self) -> bool {
+        !self.is_terminal() && !self.is_voting()
+    }
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
@@ -27,7 +54,11 @@ pub struct MembershipProposal<
     id: (BankId, PropId),
     tribute: Currency,
     shares_requested: Shares,
+    // non-voting economic shares
+    loot_requested: Shares,
     applicant: AccountId,
+    // member vouching for the applicant and the bond they reserved
+    sponsor_bond: Option<(AccountId, Currency)>,
     state: State,
 }
 
@@ -53,13 +84,16 @@ impl<
         prop_id: PropId,
         tribute: Currency,
         shares_requested: Shares,
+        loot_requested: Shares,
         applicant: AccountId,
     ) -> Self {
         Self {
             id: (bank_id, prop_id),
             tribute,
             shares_requested,
+            loot_requested,
             applicant,
+            sponsor_bond: None,
             state: ProposalState::WaitingForApproval,
         }
     }
@@ -75,9 +109,59 @@ impl<
     pub fn shares_requested(&self) -> Shares {
         self.shares_requested
     }
+    pub fn loot_requested(&self) -> Shares {
+        self.loot_requested
+    }
     pub fn applicant(&self) -> AccountId {
         self.applicant.clone()
     }
+    pub fn sponsor_bond(&self) -> Option<(AccountId, Currency)> {
+        self.sponsor_bond.clone()
+    }
+    pub fn set_sponsor_bond(&self, sponsor: AccountId, bond: Currency) -> Self {
+        Self {
+            sponsor_bond: Some((sponsor, bond)),
+            ..self.clone()
+        }
+    }
+    pub fn state(&self) -> ProposalState<VoteId> {
+        self.state
+    }
+    pub fn set_state(&self, state: ProposalState<VoteId>) -> Self {
+        Self {
+            state,
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Proposal to kick a member, converting their voting shares into loot
+pub struct KickProposal<BankId, PropId, AccountId, State> {
+    id: (BankId, PropId),
+    target: AccountId,
+    state: State,
+}
+
+impl<BankId: Copy, PropId: Copy, AccountId: Clone, VoteId: Copy>
+    KickProposal<BankId, PropId, AccountId, ProposalState<VoteId>>
+{
+    pub fn new(bank_id: BankId, prop_id: PropId, target: AccountId) -> Self {
+        Self {
+            id: (bank_id, prop_id),
+            target,
+            state: ProposalState::WaitingForApproval,
+        }
+    }
+    pub fn bank_id(&self) -> BankId {
+        self.id.0
+    }
+    pub fn prop_id(&self) -> PropId {
+        self.id.1
+    }
+    pub fn target(&self) -> AccountId {
+        self.target.clone()
+    }
     pub fn state(&self) -> ProposalState<VoteId> {
         self.state
     }
@@ -88,3 +172,67 @@ impl<
         }
     }
 }
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Open proposal still awaiting an action from a member
+pub enum PendingAction<SpendId, MemId, VoteId> {
+    /// Spend proposal in a live vote the member has not voted in
+    VoteOnSpend(SpendId, VoteId),
+    /// Membership proposal in a live vote the member has not voted in
+    VoteOnMember(MemId, VoteId),
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// What caused a proposal to be polled towards resolution
+pub enum ResolutionTrigger<AccountId> {
+    /// Scheduled polling when blocks are initialized
+    Auto,
+    /// An account processing or poking the proposal
+    Member(AccountId),
+    /// The bank controller approving it directly
+    Sudo(AccountId),
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Entry in a bank's recent activity log
+pub enum BankActivity<SpendId, MemId, VoteId> {
+    Opened,
+    SpendProposed(SpendId),
+    SpendVoteTriggered(SpendId, VoteId),
+    /// Spend proposal left the active set, executed or not
+    SpendResolved(SpendId),
+    MemberProposed(MemId),
+    MemberVoteTriggered(MemId, VoteId),
+    Closed,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Bank state with its balances, live proposals and configuration, read in one call
+pub struct BankSnapshot<Bank, SpendId, MemId, ThresholdId, Balance, BlockNumber>
+{
+    pub bank: Bank,
+    /// Total balance of the bank account, including reserved funds
+    pub balance: Balance,
+    pub free: Balance,
+    /// Funds reserved for spends in a live vote
+    pub reserved: Balance,
+    /// Free balance above the minimum deposit
+    pub available: Balance,
+    pub active_spends: Vec<SpendId>,
+    pub active_member_proposals: Vec<MemId>,
+    /// Threshold spend votes are dispatched with
+    pub spend_threshold: ThresholdId,
+    /// Threshold membership votes are dispatched with by default
+    pub member_threshold: ThresholdId,
+    pub spend_poll_frequency: BlockNumber,
+    pub member_poll_frequency: BlockNumber,
+}
+
+#[derive(Clone, Copy, Default, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+/// Running totals of a member's contributions to a bank
+pub struct MemberStat<Balance> {
+    pub tribute_paid: Balance,
+    pub spends_proposed: u32,
+    pub spends_passed: u32,
+    pub votes_cast: u32,
+}
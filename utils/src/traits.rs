@@ -159,6 +159,8 @@ pub trait ConfigureThreshold<Threshold, Hash, BlockNumber> {
     type ThresholdId;
     type VoteId; // TODO: make this same as OpenVote type by merging traits someday somehow
     fn register_threshold(t: Threshold) -> Result<Self::ThresholdId>;
+    /// The threshold registered under `id`, if any
+    fn threshold(id: Self::ThresholdId) -> Option<Threshold>;
     fn invoke_threshold(
         id: Self::ThresholdId,
         topic: Option<Hash>,
@@ -166,6 +168,39 @@ pub trait ConfigureThreshold<Threshold, Hash, BlockNumber> {
     ) -> Result<Self::VoteId>;
 }
 
+/// Participation in a vote, for turnout requirements and voter prompts
+pub trait VoteParticipation<VoteId, AccountId, Signal> {
+    /// Returns (turnout, all possible turnout) if the vote exists
+    fn turnout(vote_id: VoteId) -> Option<(Signal, Signal)>;
+    /// Returns (in favor, against) if the vote exists
+    fn tally(vote_id: VoteId) -> Option<(Signal, Signal)>;
+    /// True if `who` is eligible to vote but has not yet
+    fn awaits_vote(vote_id: VoteId, who: &AccountId) -> bool;
+    /// Accounts that have cast a vote
+    fn voters(vote_id: VoteId) -> Vec<AccountId>;
+}
+
+/// Cancel a live vote before it concludes
+pub trait CloseVote<VoteId> {
+    /// Removes the vote and its votes so it accepts no more and reports no outcome
+    fn close_vote(vote_id: VoteId) -> DispatchResult;
+}
+
+/// Votes where voters may lock their shares to amplify their signal
+pub trait ConvictionVote<OrgId, VoteId, AccountId, BlockNumber> {
+    /// Lets voters on `vote_id` lock their shares in `org` for multiples of `lock_period`
+    fn enable_conviction(
+        vote_id: VoteId,
+        org: OrgId,
+        lock_period: BlockNumber,
+    ) -> DispatchResult;
+    /// The block until which conviction votes lock the shares of `who` in `org`
+    fn conviction_locked_until(
+        org: OrgId,
+        who: &AccountId,
+    ) -> Option<BlockNumber>;
+}
+
 pub trait UpdateVote<VoteId, Hash, BlockNumber> {
     fn update_vote_topic(
         vote_id: VoteId,
@@ -289,6 +324,16 @@ pub trait SpendGovernance<BankId, Currency, AccountId, SProp> {
     fn poll_spend_proposal(prop: SProp) -> Result<Self::SpendState>;
 }
 
+/// Source of flags read by `SpendCondition::IfExternalFlag`
+pub trait ExternalFlag<Key> {
+    fn is_set(key: &Key) -> bool;
+}
+impl<Key> ExternalFlag<Key> for () {
+    fn is_set(_key: &Key) -> bool {
+        false
+    }
+}
+
 // TODO: merge functionality with SpendGovernance
 pub trait DocGovernance<CommitteeId, Cid, AccountId, Proposal> {
     type ProposalId;
@@ -322,6 +367,7 @@ pub trait MolochMembership<AccountId, BankId, Currency, Shares, MProp> {
         bank_id: BankId,
         tribute: Currency,
         shares_requested: Shares,
+        loot_requested: Shares,
         applicant: AccountId,
     ) -> Result<Self::MemberPropId>;
     fn _trigger_vote_on_member_proposal(
@@ -332,3 +378,13 @@ pub trait MolochMembership<AccountId, BankId, Currency, Shares, MProp> {
     fn poll_membership_proposal(prop: MProp) -> Result<Self::PropState>;
     fn _burn_shares(caller: AccountId, bank_id: BankId) -> DispatchResult;
 }
+
+pub trait Ragequit<AccountId, BankId, Shares, Currency> {
+    /// Burns the shares (all if `None`) for their portion of the bank, returning
+    /// the amount paid out and the fee retained by the bank
+    fn ragequit(
+        caller: &AccountId,
+        bank_id: BankId,
+        shares_to_burn: Option<Shares>,
+    ) -> Result<(Currency, Currency)>;
+}
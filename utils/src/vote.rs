@@ -32,6 +32,48 @@ impl Default for VoterView {
     }
 }
 
+#[derive(
+    Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// How long a voter locks their shares in exchange for more vote weight
+pub enum Conviction {
+    /// Base weight, no lock
+    None,
+    /// Double weight, locked for one period
+    Locked2x,
+    /// Triple weight, locked for two periods
+    Locked3x,
+    /// Quadruple weight, locked for four periods
+    Locked4x,
+}
+
+impl Conviction {
+    /// The factor applied to the voter's signal
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            Conviction::None => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 3,
+            Conviction::Locked4x => 4,
+        }
+    }
+    /// The number of lock periods the voter's shares stay locked after voting
+    pub fn lock_periods(&self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked2x => 1,
+            Conviction::Locked3x => 2,
+            Conviction::Locked4x => 4,
+        }
+    }
+}
+
+impl Default for Conviction {
+    fn default() -> Conviction {
+        Conviction::None
+    }
+}
+
 #[derive(
     new, Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
 )]